@@ -16,6 +16,7 @@ extern crate itertools;
 
 use itertools::Itertools;
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{self, Read, Write};
 use std::str;
@@ -140,16 +141,30 @@ fn gen_file(
 
 fn gen_colors() -> Result<()> {
     let map_name = "COLORS";
+    let names_map_name = "COLOR_NAMES";
     let struct_name = "Color";
 
     let mut spec = String::new();
     fs::File::open("spec/colors.txt")?.read_to_string(&mut spec)?;
 
     let mut map = phf_codegen::Map::new();
+    // A reverse map. If a color has multiple names, like `gray` and `grey`,
+    // then the shortest one is used.
+    let mut names: BTreeMap<(u8, u8, u8), &str> = BTreeMap::new();
     for (line1, line2) in spec.split('\n').filter(|s| !s.is_empty()).tuples() {
         let rgb: Vec<&str> = line2.split(',').collect();
-        map.entry(line1, &format!("{}{{ red: {}, green: {}, blue: {} }}",
+        map.entry(line1, &format!("{}{{ red: {}, green: {}, blue: {}, alpha: 255 }}",
                                   struct_name, rgb[0], rgb[1], rgb[2]));
+
+        let key = (rgb[0].parse().unwrap(), rgb[1].parse().unwrap(), rgb[2].parse().unwrap());
+        let is_better = match names.get(&key) {
+            Some(prev) => (line1.len(), line1) < (prev.len(), *prev),
+            None => true,
+        };
+
+        if is_better {
+            names.insert(key, line1);
+        }
     }
 
     let mut map_data = Vec::new();
@@ -169,6 +184,23 @@ fn gen_colors() -> Result<()> {
     writeln!(f, "    {}.get(text).cloned()", map_name)?;
     writeln!(f, "}}")?;
 
+    writeln!(f, "\npub fn rgb_color_entry_from_name(text: &str) -> Option<(&'static str, {})> {{",
+             struct_name)?;
+    writeln!(f, "    {}.get_entry(text).map(|(k, v)| (*k, *v))", map_name)?;
+    writeln!(f, "}}")?;
+
+    // Sorted by color, so we can use a binary search.
+    writeln!(f, "\nstatic {}: &[((u8, u8, u8), &str)] = &[", names_map_name)?;
+    for (&(r, g, b), name) in &names {
+        writeln!(f, "    (({}, {}, {}), \"{}\"),", r, g, b, name)?;
+    }
+    writeln!(f, "];")?;
+
+    writeln!(f, "\npub fn rgb_color_to_name(red: u8, green: u8, blue: u8) -> Option<&'static str> {{")?;
+    writeln!(f, "    {}.binary_search_by_key(&(red, green, blue), |v| v.0).ok().map(|i| {}[i].1)",
+             names_map_name, names_map_name)?;
+    writeln!(f, "}}")?;
+
     Ok(())
 }
 
@@ -185,10 +185,10 @@ fn parse_svg_attribute(
 
             for token in tokenizer {
                 match token? {
-                    style::Token::XmlAttribute(name, value) => {
+                    style::Token::XmlAttribute(name, value, _) => {
                         print_indent!("Non-SVG attribute: {} = '{}'", depth + 1, name, value);
                     }
-                    style::Token::SvgAttribute(aid, value) => {
+                    style::Token::SvgAttribute(aid, value, _) => {
                         parse_svg_attribute(eid, "", aid, value, depth + 1)?;
                     }
                     style::Token::EntityRef(name) => {
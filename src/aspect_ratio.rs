@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::fmt;
 use std::str::FromStr;
 
 use xmlparser::{
@@ -116,3 +117,32 @@ impl FromStr for AspectRatio {
         AspectRatio::from_span(StrSpan::from_str(text))
     }
 }
+
+impl fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.defer {
+            write!(f, "defer ")?;
+        }
+
+        let align = match self.align {
+            Align::None => "none",
+            Align::XMinYMin => "xMinYMin",
+            Align::XMidYMin => "xMidYMin",
+            Align::XMaxYMin => "xMaxYMin",
+            Align::XMinYMid => "xMinYMid",
+            Align::XMidYMid => "xMidYMid",
+            Align::XMaxYMid => "xMaxYMid",
+            Align::XMinYMax => "xMinYMax",
+            Align::XMidYMax => "xMidYMax",
+            Align::XMaxYMax => "xMaxYMax",
+        };
+
+        write!(f, "{}", align)?;
+
+        if self.slice {
+            write!(f, " slice")?;
+        }
+
+        Ok(())
+    }
+}
@@ -204,7 +204,9 @@ pub enum AttributeId {
     Ry,
     Scale,
     Seed,
+    ShapeInside,
     ShapeRendering,
+    ShapeSubtract,
     Show,
     Slope,
     Space,
@@ -285,332 +287,334 @@ pub enum AttributeId {
 }
 
 static ATTRIBUTES: ::phf::Map<&'static str, AttributeId> = ::phf::Map {
-    key: 1897749892740154578,
+    key: 1897749892740154579,
     disps: ::phf::Slice::Static(&[
-        (0, 71),
-        (0, 2),
-        (0, 6),
-        (0, 0),
-        (0, 20),
-        (0, 17),
-        (1, 148),
-        (0, 5),
-        (0, 23),
-        (0, 102),
+        (0, 210),
+        (0, 47),
+        (1, 58),
         (0, 5),
+        (2, 17),
+        (1, 46),
+        (0, 67),
         (0, 1),
-        (0, 213),
-        (1, 0),
-        (1, 203),
-        (0, 192),
+        (0, 208),
         (0, 0),
-        (0, 15),
-        (0, 54),
-        (0, 63),
-        (0, 20),
-        (0, 7),
-        (0, 145),
-        (3, 182),
-        (6, 61),
-        (0, 47),
-        (0, 156),
-        (1, 48),
-        (1, 51),
-        (1, 246),
-        (0, 83),
-        (0, 94),
-        (0, 210),
-        (40, 177),
-        (0, 75),
-        (2, 37),
-        (0, 1),
-        (0, 4),
-        (2, 10),
-        (3, 43),
         (2, 58),
-        (0, 76),
-        (13, 55),
-        (0, 25),
-        (0, 11),
-        (18, 112),
-        (0, 13),
-        (16, 97),
-        (0, 1),
+        (0, 0),
+        (2, 49),
+        (3, 41),
+        (12, 167),
+        (19, 226),
+        (18, 227),
+        (0, 4),
+        (1, 34),
+        (0, 14),
+        (0, 10),
+        (1, 111),
+        (0, 0),
+        (8, 40),
+        (0, 0),
+        (1, 95),
+        (0, 106),
+        (0, 5),
+        (3, 3),
+        (0, 9),
+        (0, 32),
+        (1, 5),
+        (11, 79),
+        (0, 5),
+        (0, 2),
+        (0, 12),
+        (85, 238),
+        (1, 229),
+        (0, 0),
+        (0, 248),
+        (0, 9),
+        (0, 27),
+        (0, 0),
+        (0, 124),
+        (4, 117),
         (0, 6),
-        (8, 20),
-        (0, 11),
-        (2, 129),
-        (23, 124),
+        (0, 131),
+        (0, 43),
+        (37, 230),
+        (0, 0),
+        (0, 36),
+        (0, 60),
+        (5, 37),
+        (0, 5),
     ]),
     entries: ::phf::Slice::Static(&[
-        ("radius", AttributeId::Radius),
-        ("cap-height", AttributeId::CapHeight),
-        ("limitingConeAngle", AttributeId::LimitingConeAngle),
-        ("g2", AttributeId::G2),
-        ("class", AttributeId::Class),
-        ("keyTimes", AttributeId::KeyTimes),
-        ("keyPoints", AttributeId::KeyPoints),
-        ("patternUnits", AttributeId::PatternUnits),
-        ("display", AttributeId::Display),
-        ("color-interpolation", AttributeId::ColorInterpolation),
-        ("v-hanging", AttributeId::VHanging),
+        ("surfaceScale", AttributeId::SurfaceScale),
+        ("string", AttributeId::String),
+        ("additive", AttributeId::Additive),
+        ("from", AttributeId::From),
+        ("font-family", AttributeId::FontFamily),
+        ("font-stretch", AttributeId::FontStretch),
+        ("diffuseConstant", AttributeId::DiffuseConstant),
+        ("opacity", AttributeId::Opacity),
+        ("kerning", AttributeId::Kerning),
+        ("systemLanguage", AttributeId::SystemLanguage),
+        ("requiredExtensions", AttributeId::RequiredExtensions),
+        ("clip-rule", AttributeId::ClipRule),
+        ("method", AttributeId::Method),
         ("type", AttributeId::Type),
-        ("color-profile", AttributeId::ColorProfile),
-        ("version", AttributeId::Version),
-        ("k3", AttributeId::K3),
-        ("stroke-opacity", AttributeId::StrokeOpacity),
-        ("k2", AttributeId::K2),
-        ("format", AttributeId::Format),
+        ("actuate", AttributeId::Actuate),
+        ("text-rendering", AttributeId::TextRendering),
+        ("show", AttributeId::Show),
+        ("shape-subtract", AttributeId::ShapeSubtract),
+        ("fy", AttributeId::Fy),
+        ("azimuth", AttributeId::Azimuth),
+        ("color-rendering", AttributeId::ColorRendering),
+        ("mathematical", AttributeId::Mathematical),
+        ("glyph-orientation-horizontal", AttributeId::GlyphOrientationHorizontal),
+        ("origin", AttributeId::Origin),
+        ("stroke-linejoin", AttributeId::StrokeLinejoin),
+        ("restart", AttributeId::Restart),
+        ("mode", AttributeId::Mode),
         ("k1", AttributeId::K1),
-        ("exponent", AttributeId::Exponent),
-        ("base", AttributeId::Base),
-        ("dur", AttributeId::Dur),
-        ("color-interpolation-filters", AttributeId::ColorInterpolationFilters),
-        ("attributeType", AttributeId::AttributeType),
-        ("onresize", AttributeId::Onresize),
-        ("fill", AttributeId::Fill),
-        ("width", AttributeId::Width),
+        ("onmouseout", AttributeId::Onmouseout),
+        ("x2", AttributeId::X2),
+        ("dx", AttributeId::Dx),
+        ("repeatCount", AttributeId::RepeatCount),
+        ("tableValues", AttributeId::TableValues),
+        ("alignment-baseline", AttributeId::AlignmentBaseline),
+        ("writing-mode", AttributeId::WritingMode),
+        ("font-variant", AttributeId::FontVariant),
+        ("stitchTiles", AttributeId::StitchTiles),
+        ("units-per-em", AttributeId::UnitsPerEm),
+        ("mask", AttributeId::Mask),
+        ("media", AttributeId::Media),
+        ("font-style", AttributeId::FontStyle),
+        ("k3", AttributeId::K3),
+        ("refX", AttributeId::RefX),
+        ("keySplines", AttributeId::KeySplines),
+        ("primitiveUnits", AttributeId::PrimitiveUnits),
+        ("underline-position", AttributeId::UnderlinePosition),
+        ("onzoom", AttributeId::Onzoom),
+        ("textLength", AttributeId::TextLength),
+        ("local", AttributeId::Local),
+        ("kernelUnitLength", AttributeId::KernelUnitLength),
+        ("amplitude", AttributeId::Amplitude),
         ("to", AttributeId::To),
-        ("values", AttributeId::Values),
+        ("gradientUnits", AttributeId::GradientUnits),
+        ("marker-start", AttributeId::MarkerStart),
+        ("widths", AttributeId::Widths),
+        ("transform", AttributeId::Transform),
+        ("stroke-miterlimit", AttributeId::StrokeMiterlimit),
+        ("marker-mid", AttributeId::MarkerMid),
+        ("name", AttributeId::Name),
         ("patternTransform", AttributeId::PatternTransform),
-        ("pointsAtZ", AttributeId::PointsAtZ),
-        ("string", AttributeId::String),
+        ("yChannelSelector", AttributeId::YChannelSelector),
+        ("version", AttributeId::Version),
+        ("marker-end", AttributeId::MarkerEnd),
+        ("stroke-linecap", AttributeId::StrokeLinecap),
+        ("line-height", AttributeId::LineHeight),
+        ("end", AttributeId::End),
+        ("format", AttributeId::Format),
+        ("direction", AttributeId::Direction),
+        ("baseline-shift", AttributeId::BaselineShift),
+        ("stroke", AttributeId::Stroke),
+        ("keyPoints", AttributeId::KeyPoints),
+        ("horiz-adv-x", AttributeId::HorizAdvX),
+        ("fill-opacity", AttributeId::FillOpacity),
+        ("baseProfile", AttributeId::BaseProfile),
+        ("lang", AttributeId::Lang),
+        ("patternUnits", AttributeId::PatternUnits),
+        ("y", AttributeId::Y),
+        ("class", AttributeId::Class),
+        ("horiz-origin-y", AttributeId::HorizOriginY),
+        ("lengthAdjust", AttributeId::LengthAdjust),
+        ("letter-spacing", AttributeId::LetterSpacing),
+        ("fill-rule", AttributeId::FillRule),
+        ("font", AttributeId::Font),
+        ("min", AttributeId::Min),
+        ("refY", AttributeId::RefY),
+        ("onload", AttributeId::Onload),
+        ("attributeType", AttributeId::AttributeType),
+        ("v-hanging", AttributeId::VHanging),
+        ("alphabetic", AttributeId::Alphabetic),
         ("clip-path", AttributeId::ClipPath),
-        ("onmouseup", AttributeId::Onmouseup),
-        ("onmousemove", AttributeId::Onmousemove),
+        ("overline-thickness", AttributeId::OverlineThickness),
+        ("accumulate", AttributeId::Accumulate),
+        ("horiz-origin-x", AttributeId::HorizOriginX),
+        ("fill", AttributeId::Fill),
+        ("flood-color", AttributeId::FloodColor),
+        ("onunload", AttributeId::Onunload),
+        ("onend", AttributeId::Onend),
         ("bbox", AttributeId::Bbox),
-        ("d", AttributeId::D),
-        ("in2", AttributeId::In2),
-        ("v-alphabetic", AttributeId::VAlphabetic),
+        ("filter", AttributeId::Filter),
         ("height", AttributeId::Height),
-        ("offset", AttributeId::Offset),
-        ("ry", AttributeId::Ry),
-        ("x1", AttributeId::X1),
-        ("dx", AttributeId::Dx),
-        ("enable-background", AttributeId::EnableBackground),
-        ("x-height", AttributeId::XHeight),
-        ("in", AttributeId::In),
-        ("maskContentUnits", AttributeId::MaskContentUnits),
-        ("scale", AttributeId::Scale),
-        ("spreadMethod", AttributeId::SpreadMethod),
-        ("additive", AttributeId::Additive),
-        ("id", AttributeId::Id),
-        ("specularExponent", AttributeId::SpecularExponent),
+        ("onactivate", AttributeId::Onactivate),
+        ("stroke-dasharray", AttributeId::StrokeDasharray),
+        ("repeatDur", AttributeId::RepeatDur),
+        ("title", AttributeId::Title),
+        ("visibility", AttributeId::Visibility),
         ("stroke-width", AttributeId::StrokeWidth),
-        ("strikethrough-thickness", AttributeId::StrikethroughThickness),
-        ("fy", AttributeId::Fy),
-        ("begin", AttributeId::Begin),
-        ("onrepeat", AttributeId::Onrepeat),
-        ("vert-origin-x", AttributeId::VertOriginX),
+        ("scale", AttributeId::Scale),
+        ("points", AttributeId::Points),
+        ("by", AttributeId::By),
+        ("divisor", AttributeId::Divisor),
         ("font-size-adjust", AttributeId::FontSizeAdjust),
-        ("onclick", AttributeId::Onclick),
-        ("horiz-adv-x", AttributeId::HorizAdvX),
-        ("slope", AttributeId::Slope),
-        ("cx", AttributeId::Cx),
-        ("amplitude", AttributeId::Amplitude),
-        ("v-ideographic", AttributeId::VIdeographic),
-        ("kernelUnitLength", AttributeId::KernelUnitLength),
+        ("externalResourcesRequired", AttributeId::ExternalResourcesRequired),
+        ("values", AttributeId::Values),
+        ("targetY", AttributeId::TargetY),
+        ("arcrole", AttributeId::Arcrole),
         ("contentStyleType", AttributeId::ContentStyleType),
-        ("gradientTransform", AttributeId::GradientTransform),
-        ("writing-mode", AttributeId::WritingMode),
-        ("x", AttributeId::X),
-        ("line-height", AttributeId::LineHeight),
-        ("specularConstant", AttributeId::SpecularConstant),
-        ("requiredExtensions", AttributeId::RequiredExtensions),
-        ("alphabetic", AttributeId::Alphabetic),
-        ("edgeMode", AttributeId::EdgeMode),
+        ("id", AttributeId::Id),
+        ("zoomAndPan", AttributeId::ZoomAndPan),
+        ("vert-adv-y", AttributeId::VertAdvY),
         ("descent", AttributeId::Descent),
-        ("onload", AttributeId::Onload),
-        ("textLength", AttributeId::TextLength),
-        ("startOffset", AttributeId::StartOffset),
-        ("onerror", AttributeId::Onerror),
-        ("marker-mid", AttributeId::MarkerMid),
+        ("hanging", AttributeId::Hanging),
+        ("y2", AttributeId::Y2),
+        ("seed", AttributeId::Seed),
+        ("xlink", AttributeId::Xlink),
+        ("k2", AttributeId::K2),
+        ("base", AttributeId::Base),
+        ("lighting-color", AttributeId::LightingColor),
+        ("cy", AttributeId::Cy),
+        ("targetX", AttributeId::TargetX),
+        ("onmouseup", AttributeId::Onmouseup),
+        ("display", AttributeId::Display),
+        ("glyph-name", AttributeId::GlyphName),
+        ("patternContentUnits", AttributeId::PatternContentUnits),
+        ("k4", AttributeId::K4),
+        ("filterUnits", AttributeId::FilterUnits),
+        ("enable-background", AttributeId::EnableBackground),
+        ("dur", AttributeId::Dur),
+        ("v-alphabetic", AttributeId::VAlphabetic),
+        ("font-size", AttributeId::FontSize),
         ("ideographic", AttributeId::Ideographic),
-        ("pointsAtX", AttributeId::PointsAtX),
-        ("onabort", AttributeId::Onabort),
         ("glyph-orientation-vertical", AttributeId::GlyphOrientationVertical),
-        ("onmousedown", AttributeId::Onmousedown),
-        ("visibility", AttributeId::Visibility),
-        ("xmlns", AttributeId::Xmlns),
-        ("pathLength", AttributeId::PathLength),
-        ("alignment-baseline", AttributeId::AlignmentBaseline),
-        ("onbegin", AttributeId::Onbegin),
-        ("contentScriptType", AttributeId::ContentScriptType),
-        ("actuate", AttributeId::Actuate),
-        ("x2", AttributeId::X2),
-        ("hanging", AttributeId::Hanging),
+        ("slope", AttributeId::Slope),
+        ("exponent", AttributeId::Exponent),
+        ("rotate", AttributeId::Rotate),
+        ("u1", AttributeId::U1),
+        ("order", AttributeId::Order),
+        ("pointsAtZ", AttributeId::PointsAtZ),
+        ("preserveAspectRatio", AttributeId::PreserveAspectRatio),
+        ("onerror", AttributeId::Onerror),
+        ("text-anchor", AttributeId::TextAnchor),
+        ("onscroll", AttributeId::Onscroll),
+        ("viewBox", AttributeId::ViewBox),
+        ("onresize", AttributeId::Onresize),
         ("strikethrough-position", AttributeId::StrikethroughPosition),
-        ("arcrole", AttributeId::Arcrole),
-        ("letter-spacing", AttributeId::LetterSpacing),
-        ("direction", AttributeId::Direction),
-        ("azimuth", AttributeId::Azimuth),
-        ("seed", AttributeId::Seed),
-        ("onzoom", AttributeId::Onzoom),
-        ("baseline-shift", AttributeId::BaselineShift),
+        ("stroke-dashoffset", AttributeId::StrokeDashoffset),
+        ("stroke-opacity", AttributeId::StrokeOpacity),
+        ("k", AttributeId::K),
+        ("d", AttributeId::D),
+        ("underline-thickness", AttributeId::UnderlineThickness),
+        ("onmouseover", AttributeId::Onmouseover),
+        ("onbegin", AttributeId::Onbegin),
+        ("accent-height", AttributeId::AccentHeight),
         ("cursor", AttributeId::Cursor),
-        ("onend", AttributeId::Onend),
-        ("z", AttributeId::Z),
-        ("v-mathematical", AttributeId::VMathematical),
-        ("attributeName", AttributeId::AttributeName),
-        ("rendering-intent", AttributeId::RenderingIntent),
-        ("y1", AttributeId::Y1),
-        ("xChannelSelector", AttributeId::XChannelSelector),
-        ("name", AttributeId::Name),
-        ("href", AttributeId::Href),
-        ("baseFrequency", AttributeId::BaseFrequency),
-        ("viewBox", AttributeId::ViewBox),
-        ("show", AttributeId::Show),
-        ("font-style", AttributeId::FontStyle),
-        ("dy", AttributeId::Dy),
-        ("filterRes", AttributeId::FilterRes),
-        ("stop-color", AttributeId::StopColor),
+        ("in", AttributeId::In),
+        ("x-height", AttributeId::XHeight),
         ("shape-rendering", AttributeId::ShapeRendering),
-        ("overline-thickness", AttributeId::OverlineThickness),
-        ("u1", AttributeId::U1),
-        ("zoomAndPan", AttributeId::ZoomAndPan),
-        ("onmouseover", AttributeId::Onmouseover),
-        ("origin", AttributeId::Origin),
-        ("marker", AttributeId::Marker),
-        ("refX", AttributeId::RefX),
-        ("font-size", AttributeId::FontSize),
-        ("clip", AttributeId::Clip),
-        ("fill-opacity", AttributeId::FillOpacity),
-        ("marker-start", AttributeId::MarkerStart),
-        ("mathematical", AttributeId::Mathematical),
-        ("orientation", AttributeId::Orientation),
+        ("maskContentUnits", AttributeId::MaskContentUnits),
+        ("r", AttributeId::R),
+        ("space", AttributeId::Space),
+        ("kernelMatrix", AttributeId::KernelMatrix),
+        ("keyTimes", AttributeId::KeyTimes),
+        ("unicode", AttributeId::Unicode),
         ("stemv", AttributeId::Stemv),
-        ("text-anchor", AttributeId::TextAnchor),
-        ("color", AttributeId::Color),
-        ("image-rendering", AttributeId::ImageRendering),
-        ("calcMode", AttributeId::CalcMode),
-        ("onfocusout", AttributeId::Onfocusout),
-        ("markerUnits", AttributeId::MarkerUnits),
-        ("points", AttributeId::Points),
-        ("underline-thickness", AttributeId::UnderlineThickness),
-        ("horiz-origin-y", AttributeId::HorizOriginY),
-        ("operator", AttributeId::Operator),
-        ("title", AttributeId::Title),
-        ("unicode-range", AttributeId::UnicodeRange),
-        ("pointsAtY", AttributeId::PointsAtY),
-        ("divisor", AttributeId::Divisor),
         ("elevation", AttributeId::Elevation),
-        ("keySplines", AttributeId::KeySplines),
-        ("clip-rule", AttributeId::ClipRule),
-        ("markerWidth", AttributeId::MarkerWidth),
-        ("refY", AttributeId::RefY),
+        ("onabort", AttributeId::Onabort),
+        ("onmousemove", AttributeId::Onmousemove),
+        ("startOffset", AttributeId::StartOffset),
+        ("result", AttributeId::Result),
+        ("glyphRef", AttributeId::GlyphRef),
+        ("limitingConeAngle", AttributeId::LimitingConeAngle),
+        ("x1", AttributeId::X1),
+        ("cx", AttributeId::Cx),
+        ("rx", AttributeId::Rx),
+        ("calcMode", AttributeId::CalcMode),
+        ("strikethrough-thickness", AttributeId::StrikethroughThickness),
+        ("gradientTransform", AttributeId::GradientTransform),
+        ("pathLength", AttributeId::PathLength),
+        ("href", AttributeId::Href),
+        ("rendering-intent", AttributeId::RenderingIntent),
+        ("color-profile", AttributeId::ColorProfile),
         ("word-spacing", AttributeId::WordSpacing),
-        ("spacing", AttributeId::Spacing),
-        ("overline-position", AttributeId::OverlinePosition),
+        ("edgeMode", AttributeId::EdgeMode),
+        ("stop-color", AttributeId::StopColor),
+        ("dy", AttributeId::Dy),
+        ("maskUnits", AttributeId::MaskUnits),
+        ("y1", AttributeId::Y1),
         ("viewTarget", AttributeId::ViewTarget),
-        ("accent-height", AttributeId::AccentHeight),
-        ("glyph-orientation-horizontal", AttributeId::GlyphOrientationHorizontal),
-        ("mask", AttributeId::Mask),
-        ("kernelMatrix", AttributeId::KernelMatrix),
-        ("font-stretch", AttributeId::FontStretch),
-        ("filterUnits", AttributeId::FilterUnits),
-        ("onscroll", AttributeId::Onscroll),
-        ("method", AttributeId::Method),
-        ("local", AttributeId::Local),
-        ("targetY", AttributeId::TargetY),
-        ("glyph-name", AttributeId::GlyphName),
-        ("filter", AttributeId::Filter),
-        ("end", AttributeId::End),
-        ("font-variant", AttributeId::FontVariant),
-        ("restart", AttributeId::Restart),
-        ("intercept", AttributeId::Intercept),
-        ("onunload", AttributeId::Onunload),
-        ("preserveAlpha", AttributeId::PreserveAlpha),
-        ("order", AttributeId::Order),
-        ("stroke-miterlimit", AttributeId::StrokeMiterlimit),
-        ("font", AttributeId::Font),
-        ("numOctaves", AttributeId::NumOctaves),
-        ("flood-color", AttributeId::FloodColor),
+        ("onrepeat", AttributeId::Onrepeat),
+        ("specularExponent", AttributeId::SpecularExponent),
+        ("xmlns", AttributeId::Xmlns),
+        ("pointsAtY", AttributeId::PointsAtY),
+        ("cap-height", AttributeId::CapHeight),
+        ("font-weight", AttributeId::FontWeight),
+        ("offset", AttributeId::Offset),
+        ("overflow", AttributeId::Overflow),
         ("style", AttributeId::Style),
-        ("k", AttributeId::K),
+        ("onfocusin", AttributeId::Onfocusin),
+        ("z", AttributeId::Z),
+        ("width", AttributeId::Width),
+        ("text-decoration", AttributeId::TextDecoration),
+        ("numOctaves", AttributeId::NumOctaves),
         ("unicode-bidi", AttributeId::UnicodeBidi),
-        ("stroke-dashoffset", AttributeId::StrokeDashoffset),
-        ("text-rendering", AttributeId::TextRendering),
-        ("glyphRef", AttributeId::GlyphRef),
+        ("overline-position", AttributeId::OverlinePosition),
+        ("vert-origin-x", AttributeId::VertOriginX),
+        ("color", AttributeId::Color),
+        ("stemh", AttributeId::Stemh),
+        ("panose-1", AttributeId::Panose1),
+        ("vert-origin-y", AttributeId::VertOriginY),
+        ("stdDeviation", AttributeId::StdDeviation),
+        ("x", AttributeId::X),
         ("bias", AttributeId::Bias),
-        ("patternContentUnits", AttributeId::PatternContentUnits),
-        ("stop-opacity", AttributeId::StopOpacity),
-        ("requiredFeatures", AttributeId::RequiredFeatures),
-        ("text-decoration", AttributeId::TextDecoration),
-        ("onactivate", AttributeId::Onactivate),
-        ("rotate", AttributeId::Rotate),
+        ("ry", AttributeId::Ry),
+        ("image-rendering", AttributeId::ImageRendering),
+        ("spreadMethod", AttributeId::SpreadMethod),
         ("ascent", AttributeId::Ascent),
-        ("stitchTiles", AttributeId::StitchTiles),
-        ("stemh", AttributeId::Stemh),
-        ("dominant-baseline", AttributeId::DominantBaseline),
-        ("yChannelSelector", AttributeId::YChannelSelector),
-        ("fx", AttributeId::Fx),
-        ("transform", AttributeId::Transform),
         ("clipPathUnits", AttributeId::ClipPathUnits),
-        ("baseProfile", AttributeId::BaseProfile),
-        ("min", AttributeId::Min),
-        ("kerning", AttributeId::Kerning),
-        ("max", AttributeId::Max),
-        ("y", AttributeId::Y),
-        ("gradientUnits", AttributeId::GradientUnits),
+        ("v-mathematical", AttributeId::VMathematical),
+        ("markerUnits", AttributeId::MarkerUnits),
         ("orient", AttributeId::Orient),
-        ("units-per-em", AttributeId::UnitsPerEm),
-        ("pointer-events", AttributeId::PointerEvents),
-        ("r", AttributeId::R),
-        ("panose-1", AttributeId::Panose1),
-        ("color-rendering", AttributeId::ColorRendering),
-        ("widths", AttributeId::Widths),
-        ("onfocusin", AttributeId::Onfocusin),
-        ("marker-end", AttributeId::MarkerEnd),
-        ("primitiveUnits", AttributeId::PrimitiveUnits),
-        ("k4", AttributeId::K4),
-        ("horiz-origin-x", AttributeId::HorizOriginX),
-        ("from", AttributeId::From),
-        ("fill-rule", AttributeId::FillRule),
-        ("stroke-linecap", AttributeId::StrokeLinecap),
-        ("tableValues", AttributeId::TableValues),
-        ("font-weight", AttributeId::FontWeight),
-        ("stdDeviation", AttributeId::StdDeviation),
-        ("role", AttributeId::Role),
-        ("lighting-color", AttributeId::LightingColor),
-        ("media", AttributeId::Media),
-        ("stroke", AttributeId::Stroke),
-        ("lang", AttributeId::Lang),
+        ("xChannelSelector", AttributeId::XChannelSelector),
+        ("spacing", AttributeId::Spacing),
+        ("in2", AttributeId::In2),
+        ("operator", AttributeId::Operator),
+        ("u2", AttributeId::U2),
+        ("intercept", AttributeId::Intercept),
+        ("contentScriptType", AttributeId::ContentScriptType),
+        ("filterRes", AttributeId::FilterRes),
+        ("marker", AttributeId::Marker),
+        ("onfocusout", AttributeId::Onfocusout),
+        ("attributeName", AttributeId::AttributeName),
+        ("v-ideographic", AttributeId::VIdeographic),
+        ("orientation", AttributeId::Orientation),
+        ("fx", AttributeId::Fx),
+        ("markerWidth", AttributeId::MarkerWidth),
         ("target", AttributeId::Target),
-        ("maskUnits", AttributeId::MaskUnits),
-        ("accumulate", AttributeId::Accumulate),
-        ("targetX", AttributeId::TargetX),
-        ("underline-position", AttributeId::UnderlinePosition),
-        ("onmouseout", AttributeId::Onmouseout),
-        ("lengthAdjust", AttributeId::LengthAdjust),
-        ("path", AttributeId::Path),
-        ("externalResourcesRequired", AttributeId::ExternalResourcesRequired),
-        ("result", AttributeId::Result),
-        ("repeatDur", AttributeId::RepeatDur),
+        ("requiredFeatures", AttributeId::RequiredFeatures),
+        ("onmousedown", AttributeId::Onmousedown),
+        ("color-interpolation-filters", AttributeId::ColorInterpolationFilters),
         ("flood-opacity", AttributeId::FloodOpacity),
-        ("by", AttributeId::By),
+        ("unicode-range", AttributeId::UnicodeRange),
+        ("pointsAtX", AttributeId::PointsAtX),
+        ("shape-inside", AttributeId::ShapeInside),
+        ("preserveAlpha", AttributeId::PreserveAlpha),
+        ("role", AttributeId::Role),
+        ("g2", AttributeId::G2),
+        ("specularConstant", AttributeId::SpecularConstant),
         ("markerHeight", AttributeId::MarkerHeight),
-        ("preserveAspectRatio", AttributeId::PreserveAspectRatio),
-        ("space", AttributeId::Space),
-        ("font-family", AttributeId::FontFamily),
-        ("g1", AttributeId::G1),
-        ("mode", AttributeId::Mode),
-        ("opacity", AttributeId::Opacity),
-        ("repeatCount", AttributeId::RepeatCount),
-        ("diffuseConstant", AttributeId::DiffuseConstant),
-        ("vert-origin-y", AttributeId::VertOriginY),
-        ("overflow", AttributeId::Overflow),
-        ("systemLanguage", AttributeId::SystemLanguage),
-        ("unicode", AttributeId::Unicode),
-        ("rx", AttributeId::Rx),
-        ("surfaceScale", AttributeId::SurfaceScale),
-        ("xlink", AttributeId::Xlink),
-        ("stroke-dasharray", AttributeId::StrokeDasharray),
-        ("stroke-linejoin", AttributeId::StrokeLinejoin),
-        ("vert-adv-y", AttributeId::VertAdvY),
-        ("y2", AttributeId::Y2),
-        ("cy", AttributeId::Cy),
         ("arabic-form", AttributeId::ArabicForm),
-        ("u2", AttributeId::U2),
+        ("path", AttributeId::Path),
+        ("begin", AttributeId::Begin),
+        ("pointer-events", AttributeId::PointerEvents),
+        ("g1", AttributeId::G1),
+        ("dominant-baseline", AttributeId::DominantBaseline),
+        ("max", AttributeId::Max),
+        ("clip", AttributeId::Clip),
+        ("color-interpolation", AttributeId::ColorInterpolation),
+        ("baseFrequency", AttributeId::BaseFrequency),
+        ("radius", AttributeId::Radius),
+        ("onclick", AttributeId::Onclick),
+        ("stop-opacity", AttributeId::StopOpacity),
     ]),
 };
 
@@ -813,7 +817,9 @@ impl AttributeId {
             AttributeId::Ry => "ry",
             AttributeId::Scale => "scale",
             AttributeId::Seed => "seed",
+            AttributeId::ShapeInside => "shape-inside",
             AttributeId::ShapeRendering => "shape-rendering",
+            AttributeId::ShapeSubtract => "shape-subtract",
             AttributeId::Show => "show",
             AttributeId::Slope => "slope",
             AttributeId::Space => "space",
@@ -6,16 +6,20 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::str;
+use std::str::{self, FromStr};
 
 use xmlparser::{
+    self,
     FromSpan,
     Reference,
     Stream,
     StrSpan,
+    XmlByteExt,
+    XmlSpace,
 };
 
 use error::{
+    AttributeError,
     StreamError,
     StreamResult,
 };
@@ -25,13 +29,21 @@ use {
     transform,
     AspectRatio,
     AttributeId,
+    ClockValue,
     Color,
+    CommaList,
     ElementId,
+    FontShorthand,
+    KeySplines,
+    KeyTimes,
     Length,
     LengthList,
     NumberList,
     Points,
+    ShapeList,
+    SpaceList,
     StreamExt,
+    ValuesList,
     ValueId,
 };
 
@@ -50,6 +62,129 @@ impl ViewBox {
     pub fn new(x: f64, y: f64, w: f64, h: f64) -> Self {
         ViewBox { x, y, w, h }
     }
+
+    /// Parses `ViewBox` from `StrSpan`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `IncompleteViewbox` when there are less than four numbers.
+    /// - Returns `InvalidViewbox` when width or height is not positive.
+    pub fn from_span(span: StrSpan) -> StreamResult<Self> {
+        let mut stream = Stream::from_span(span);
+
+        macro_rules! parse_num {
+            () => ({
+                stream.skip_spaces();
+                if stream.at_end() {
+                    return Err(StreamError::IncompleteViewbox);
+                }
+
+                stream.parse_list_number()?
+            })
+        }
+
+        let x = parse_num!();
+        let y = parse_num!();
+        let w = parse_num!();
+        let h = parse_num!();
+
+        if w <= 0.0 || h <= 0.0 {
+            return Err(StreamError::InvalidViewbox);
+        }
+
+        Ok(ViewBox::new(x, y, w, h))
+    }
+
+    /// Compares two viewBoxes with the specified precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::ViewBox;
+    ///
+    /// let vb1 = ViewBox::new(0.0, 0.0, 100.0, 100.0);
+    /// let vb2 = ViewBox::new(0.0, 0.0, 100.0 + 1e-12, 100.0);
+    /// assert!(vb1.approx_eq(&vb2, 1e-10));
+    /// assert!(!vb1.approx_eq(&vb2, 0.0));
+    /// ```
+    pub fn approx_eq(&self, other: &ViewBox, eps: f64) -> bool {
+        let eq = |a: f64, b: f64| (a - b).abs() <= eps;
+
+           eq(self.x, other.x)
+        && eq(self.y, other.y)
+        && eq(self.w, other.w)
+        && eq(self.h, other.h)
+    }
+}
+
+impl FromStr for ViewBox {
+    type Err = StreamError;
+
+    fn from_str(text: &str) -> StreamResult<Self> {
+        ViewBox::from_span(StrSpan::from_str(text))
+    }
+}
+
+/// Representation of the [`clip`] property `rect()` shape.
+///
+/// `None` indicates `auto`.
+///
+/// [`clip`]: https://www.w3.org/TR/SVG11/masking.html#ClipProperty
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClipRect {
+    pub top: Option<Length>,
+    pub right: Option<Length>,
+    pub bottom: Option<Length>,
+    pub left: Option<Length>,
+}
+
+impl ClipRect {
+    /// Creates a new `ClipRect`.
+    pub fn new(
+        top: Option<Length>,
+        right: Option<Length>,
+        bottom: Option<Length>,
+        left: Option<Length>,
+    ) -> Self {
+        ClipRect { top, right, bottom, left }
+    }
+}
+
+
+/// Representation of the [`<FuncIRI>`] type.
+///
+/// A local reference, like `url(#id)`, has only a `fragment`.
+///
+/// [`<FuncIRI>`]: https://www.w3.org/TR/SVG/types.html#DataTypeFuncIRI
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuncIRI<'a> {
+    /// A part before `#`. `None` if not set.
+    pub base: Option<&'a str>,
+    /// A part after `#`. `None` if not set.
+    pub fragment: Option<&'a str>,
+}
+
+impl<'a> FuncIRI<'a> {
+    /// Creates a new `FuncIRI`.
+    pub fn new(base: Option<&'a str>, fragment: Option<&'a str>) -> Self {
+        FuncIRI { base, fragment }
+    }
+
+    /// Creates a new local `FuncIRI`, like `url(#id)`.
+    pub fn local(fragment: &'a str) -> Self {
+        FuncIRI { base: None, fragment: Some(fragment) }
+    }
+
+    /// Returns a `fragment` or `base`, when `fragment` is not set.
+    ///
+    /// For a local reference it's the same as the `id` from `url(#id)`.
+    pub fn link(&self) -> &'a str {
+        match (self.fragment, self.base) {
+            (Some(link), _) | (None, Some(link)) => link,
+            (None, None) => "",
+        }
+    }
 }
 
 
@@ -64,6 +199,164 @@ pub enum PaintFallback {
     Color(Color),
 }
 
+/// A [CSS-wide keyword].
+///
+/// `inherit` is a CSS-wide keyword too, but it's parsed as
+/// `AttributeValue::PredefValue(ValueId::Inherit)`, since it's a part of the SVG 1.1.
+///
+/// [CSS-wide keyword]: https://www.w3.org/TR/css-values-4/#common-keywords
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CssWideKeyword {
+    Initial,
+    Unset,
+    Revert,
+}
+
+impl CssWideKeyword {
+    /// Converts name into keyword.
+    pub fn from_name(text: &str) -> Option<CssWideKeyword> {
+        match text {
+            "initial" => Some(CssWideKeyword::Initial),
+            "unset" => Some(CssWideKeyword::Unset),
+            "revert" => Some(CssWideKeyword::Revert),
+            _ => None,
+        }
+    }
+
+    /// Converts keyword into name.
+    pub fn name(&self) -> &str {
+        match *self {
+            CssWideKeyword::Initial => "initial",
+            CssWideKeyword::Unset => "unset",
+            CssWideKeyword::Revert => "revert",
+        }
+    }
+}
+
+/// A filter primitive input.
+///
+/// Used by the [`in`] and [`in2`] attributes.
+///
+/// [`in`]: https://www.w3.org/TR/SVG11/filters.html#FilterPrimitiveInAttribute
+/// [`in2`]: https://www.w3.org/TR/SVG11/filters.html#feBlendIn2Attribute
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterInput<'a> {
+    SourceGraphic,
+    SourceAlpha,
+    BackgroundImage,
+    BackgroundAlpha,
+    FillPaint,
+    StrokePaint,
+    /// A reference to the `result` of a previous filter primitive.
+    Reference(&'a str),
+}
+
+/// Keywords are case-sensitive, so `sourceGraphic` is a reference.
+impl<'a> From<&'a str> for FilterInput<'a> {
+    fn from(text: &'a str) -> Self {
+        match text {
+            "SourceGraphic" => FilterInput::SourceGraphic,
+            "SourceAlpha" => FilterInput::SourceAlpha,
+            "BackgroundImage" => FilterInput::BackgroundImage,
+            "BackgroundAlpha" => FilterInput::BackgroundAlpha,
+            "FillPaint" => FilterInput::FillPaint,
+            "StrokePaint" => FilterInput::StrokePaint,
+            _ => FilterInput::Reference(text),
+        }
+    }
+}
+
+/// A marker [`orient`] attribute value.
+///
+/// [`orient`]: https://www.w3.org/TR/SVG2/painting.html#OrientAttribute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orient {
+    /// The `auto` keyword.
+    Auto,
+    /// The SVG 2 `auto-start-reverse` keyword.
+    AutoStartReverse,
+    /// An angle in degrees.
+    Angle(f64),
+}
+
+/// A parsed `values` attribute of the `feColorMatrix` element.
+///
+/// Can be created via [`parse_fe_color_matrix_values`].
+///
+/// [`parse_fe_color_matrix_values`]: fn.parse_fe_color_matrix_values.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorMatrix {
+    /// A 5x4 matrix in the row-major order.
+    Matrix([f64; 20]),
+    /// A saturation value.
+    Saturate(f64),
+    /// A rotation angle in degrees.
+    HueRotate(f64),
+    /// The `values` attribute is not applicable.
+    LuminanceToAlpha,
+}
+
+/// Parses the [`values`] attribute of the `feColorMatrix` element.
+///
+/// The meaning of the `values` depends on the `type` attribute,
+/// which is passed as `kind`:
+///
+/// - `matrix` requires 20 numbers.
+/// - `saturate` and `hueRotate` require a single number.
+/// - `luminanceToAlpha` ignores the `values`.
+///
+/// # Errors
+///
+/// - Returns `InvalidPredefValue` on an unknown `kind`.
+/// - Returns `InvalidColorMatrixValues` when the amount of numbers doesn't match the `kind`.
+/// - Returns `InvalidNumber` on an invalid number.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::{parse_fe_color_matrix_values, ColorMatrix};
+/// use svgparser::xmlparser::StrSpan;
+///
+/// assert_eq!(parse_fe_color_matrix_values("saturate", StrSpan::from_str("0.5")).unwrap(),
+///            ColorMatrix::Saturate(0.5));
+/// assert_eq!(parse_fe_color_matrix_values("hueRotate", StrSpan::from_str("1 2"))
+///                .unwrap_err().to_string(),
+///            "color matrix values should contain 1 number(s), not 2");
+/// ```
+///
+/// [`values`]: https://www.w3.org/TR/SVG11/filters.html#feColorMatrixValuesAttribute
+pub fn parse_fe_color_matrix_values(kind: &str, span: StrSpan) -> StreamResult<ColorMatrix> {
+    let expected = match kind {
+        "matrix" => 20,
+        "saturate" | "hueRotate" => 1,
+        "luminanceToAlpha" => return Ok(ColorMatrix::LuminanceToAlpha),
+        _ => return Err(StreamError::InvalidPredefValue(kind.into())),
+    };
+
+    let mut values = [0.0; 20];
+    let mut count = 0;
+    for n in NumberList::from_span(span) {
+        let n = n?;
+        if count < values.len() {
+            values[count] = n;
+        }
+
+        count += 1;
+    }
+
+    if count != expected {
+        return Err(StreamError::InvalidColorMatrixValues(expected, count));
+    }
+
+    Ok(match kind {
+        "matrix" => ColorMatrix::Matrix(values),
+        "saturate" => ColorMatrix::Saturate(values[0]),
+        _ => ColorMatrix::HueRotate(values[0]),
+    })
+}
+
 /// Representation of the SVG attribute value.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AttributeValue<'a> {
@@ -73,12 +366,56 @@ pub enum AttributeValue<'a> {
     Number(f64),
     /// \<list-of-numbers\> type.
     NumberList(NumberList<'a>),
+    /// [`<number-optional-number>`] type.
+    ///
+    /// If only one number is set, it will be used for both values.
+    ///
+    /// [`<number-optional-number>`]: https://www.w3.org/TR/SVG11/types.html#DataTypeNumberOptionalNumber
+    NumberOptNumber(f64, f64),
     /// [`<length>`] type.
     ///
     /// [`<length>`]: https://www.w3.org/TR/SVG/types.html#DataTypeLength
     Length(Length),
     /// \<list-of-lengths\> type.
     LengthList(LengthList<'a>),
+    /// Representation of the [`font`] shorthand property.
+    ///
+    /// [`font`]: https://www.w3.org/TR/CSS2/fonts.html#font-shorthand
+    Font(FontShorthand<'a>),
+    /// A list of shape references.
+    ///
+    /// Used by the SVG 2 `shape-inside` and `shape-subtract` properties.
+    ShapeList(ShapeList<'a>),
+    /// A `;`-separated list of animation values.
+    ///
+    /// Used by the `values` attribute of the animation elements.
+    ValuesList(ValuesList<'a>),
+    /// A list of the animation `keyTimes` values.
+    KeyTimes(KeyTimes<'a>),
+    /// A list of the animation `keySplines` values.
+    KeySplines(KeySplines<'a>),
+    /// [`<clock-value>`] type.
+    ///
+    /// Used by the `dur` and `repeatDur` attributes.
+    ///
+    /// [`<clock-value>`]: https://www.w3.org/TR/SVG11/animate.html#ClockValueSyntax
+    ClockValue(ClockValue),
+    /// A filter primitive input.
+    FilterInput(FilterInput<'a>),
+    /// A comma-separated list of strings.
+    ///
+    /// Used by the `systemLanguage` attribute.
+    CommaList(CommaList<'a>),
+    /// A space-separated list of strings.
+    ///
+    /// Used by the `requiredFeatures` and `requiredExtensions` attributes.
+    SpaceList(SpaceList<'a>),
+    /// Value of the [`xml:space`] attribute.
+    ///
+    /// [`xml:space`]: https://www.w3.org/TR/SVG11/struct.html#XMLSpaceAttribute
+    XmlSpace(XmlSpace),
+    /// A marker `orient` attribute value.
+    Orient(Orient),
     /// [`<color>`] type.
     ///
     /// [`<color>`]: https://www.w3.org/TR/SVG/types.html#DataTypeColor
@@ -87,6 +424,10 @@ pub enum AttributeValue<'a> {
     ///
     /// [`<viewBox>`]: https://www.w3.org/TR/SVG11/coords.html#ViewBoxAttribute
     ViewBox(ViewBox),
+    /// Representation of the [`clip`] property `rect()` shape.
+    ///
+    /// [`clip`]: https://www.w3.org/TR/SVG11/masking.html#ClipProperty
+    ClipRect(ClipRect),
     /// Representation of the [`preserveAspectRatio`] attribute.
     ///
     /// [`preserveAspectRatio`]: https://www.w3.org/TR/SVG/coords.html#PreserveAspectRatioAttribute
@@ -108,6 +449,10 @@ pub enum AttributeValue<'a> {
     /// [`<transform-list>`]: https://www.w3.org/TR/SVG/types.html#DataTypeTransformList
     Transform(transform::Tokenizer<'a>),
     /// Reference to the ENTITY. Contains only `name` from `&name;`.
+    ///
+    /// Emitted only when the whole value is a single reference.
+    /// Values with multiple or mixed references, like `&a;&b;` or `url(#x)&a;`,
+    /// are returned as `String`, so they can be resolved by the caller.
     EntityRef(&'a str),
     /// [`<IRI>`] type.
     ///
@@ -116,13 +461,15 @@ pub enum AttributeValue<'a> {
     /// [`<FuncIRI>`] type.
     ///
     /// [`<FuncIRI>`]: https://www.w3.org/TR/SVG/types.html#DataTypeFuncIRI
-    FuncIRI(&'a str),
+    FuncIRI(FuncIRI<'a>),
     /// [`<FuncIRI>`] type with a fallback value.
     ///
     /// [`<FuncIRI>`]: https://www.w3.org/TR/SVG/painting.html#SpecifyingPaint
-    FuncIRIWithFallback(&'a str, PaintFallback),
+    FuncIRIWithFallback(FuncIRI<'a>, PaintFallback),
     /// ID of the predefined value.
     PredefValue(ValueId),
+    /// A CSS-wide keyword of a presentation attribute.
+    CssWide(CssWideKeyword),
     /// Unknown data.
     String(&'a str),
 }
@@ -136,7 +483,8 @@ impl<'a> AttributeValue<'a> {
     ///
     /// # Errors
     ///
-    /// - Most of the `StreamError`'s can occur.
+    /// - Most of the `StreamError`'s can occur. They are wrapped into `AttributeError`
+    ///   together with `eid` and `aid`.
     /// - Data of an unknown attribute will be parsed as `AttributeValue::String` without errors.
     ///
     /// # Notes
@@ -147,17 +495,44 @@ impl<'a> AttributeValue<'a> {
     /// - `opacity` value will be bounded to 0..1 range.
     /// - This function didn't correct most of the numeric values.
     ///   Like `rect`'s negative size, etc.
-    /// - If `prefix` is not empty and `aid` is not `Href`,
-    ///   then `AttributeValue::String` will be removed.
+    /// - If `prefix` is not empty and `aid` is not `xlink:href` or `xml:space`,
+    ///   then `AttributeValue::String` will be returned.
+    /// - `marker` is a shorthand for `marker-start`, `marker-mid` and `marker-end`.
+    ///   It's parsed just like them, so use [`expand_marker_shorthand`] to get the actual values.
     ///
+    /// [`expand_marker_shorthand`]: #method.expand_marker_shorthand
     /// [presentation attributes]: https://www.w3.org/TR/SVG/propidx.html
     pub fn from_span(
         eid: ElementId,
         prefix: &str,
         aid: AttributeId,
         span: StrSpan<'a>,
-    ) -> StreamResult<AttributeValue<'a>> {
-        parse_av(eid, prefix, aid, span)
+    ) -> Result<AttributeValue<'a>, AttributeError> {
+        parse_av(eid, prefix, aid, span, false)
+            .map_err(|source| AttributeError { eid, aid, source })
+    }
+
+    /// Parses `AttributeValue` from `StrSpan` without a fallback for unknown attributes.
+    ///
+    /// The same as [`from_span`], but data of an attribute that doesn't have a parser
+    /// will produce an error instead of `AttributeValue::String`.
+    /// Useful for linters.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `AttributeError` with `StreamError::UnknownAttributeValue`
+    ///   for an attribute that doesn't have a parser.
+    /// - All errors from [`from_span`].
+    ///
+    /// [`from_span`]: #method.from_span
+    pub fn from_span_strict(
+        eid: ElementId,
+        prefix: &str,
+        aid: AttributeId,
+        span: StrSpan<'a>,
+    ) -> Result<AttributeValue<'a>, AttributeError> {
+        parse_av(eid, prefix, aid, span, true)
+            .map_err(|source| AttributeError { eid, aid, source })
     }
 
     /// Parses `AttributeValue` from string.
@@ -170,9 +545,129 @@ impl<'a> AttributeValue<'a> {
         prefix: &str,
         aid: AttributeId,
         text: &'a str,
-    ) -> StreamResult<AttributeValue<'a>> {
+    ) -> Result<AttributeValue<'a>, AttributeError> {
         AttributeValue::from_span(eid, prefix, aid, StrSpan::from_str(text))
     }
+
+    /// Returns a `Length` value or `None` if the value has a different type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::{AttributeValue, Length, LengthUnit};
+    ///
+    /// let v = AttributeValue::Length(Length::new(10.0, LengthUnit::Mm));
+    /// assert_eq!(v.as_length(), Some(Length::new(10.0, LengthUnit::Mm)));
+    /// assert_eq!(v.as_color(), None);
+    /// ```
+    pub fn as_length(&self) -> Option<Length> {
+        match *self {
+            AttributeValue::Length(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Returns a `Number` value or `None` if the value has a different type.
+    pub fn as_number(&self) -> Option<f64> {
+        match *self {
+            AttributeValue::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns a `Color` value or `None` if the value has a different type.
+    pub fn as_color(&self) -> Option<Color> {
+        match *self {
+            AttributeValue::Color(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Returns an `IRI` value or `None` if the value has a different type.
+    pub fn as_iri(&self) -> Option<&'a str> {
+        match *self {
+            AttributeValue::IRI(link) => Some(link),
+            _ => None,
+        }
+    }
+
+    /// Returns a `FuncIRI` link or `None` if the value has a different type.
+    ///
+    /// Works for `FuncIRIWithFallback` too. See `FuncIRI::link` for details.
+    pub fn as_func_iri(&self) -> Option<&'a str> {
+        match *self {
+              AttributeValue::FuncIRI(link)
+            | AttributeValue::FuncIRIWithFallback(link, _) => Some(link.link()),
+            _ => None,
+        }
+    }
+
+    /// Returns a `PredefValue` value or `None` if the value has a different type.
+    pub fn as_predef(&self) -> Option<ValueId> {
+        match *self {
+            AttributeValue::PredefValue(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Returns a span of the `String` value or `None` if the value has a different type.
+    ///
+    /// `span` must be the same span that was passed to `from_span`.
+    /// Unlike `&str`, the result preserves the position in the original text,
+    /// so the value can be parsed further with correct error positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::{AttributeId, AttributeValue, ElementId};
+    /// use svgparser::xmlparser::StrSpan;
+    ///
+    /// let text = "<text class=' big red '/>";
+    /// let span = StrSpan::from_substr(text, 13, 22);
+    /// let v = AttributeValue::from_span(ElementId::Text, "", AttributeId::Class, span).unwrap();
+    /// assert_eq!(v, AttributeValue::String("big red"));
+    /// assert_eq!(v.as_string_span(span).unwrap().start(), 14);
+    /// ```
+    pub fn as_string_span(&self, span: StrSpan<'a>) -> Option<StrSpan<'a>> {
+        match *self {
+            AttributeValue::String(s) => {
+                // A string value is either an original or a trimmed span.
+                if s.len() == span.len() {
+                    Some(span)
+                } else {
+                    Some(span.trim())
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Expands a value of the [`marker`] shorthand into the `marker-start`,
+    /// `marker-mid` and `marker-end` properties.
+    ///
+    /// The shorthand sets the same value to all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::{AttributeId, AttributeValue, ElementId, FuncIRI};
+    ///
+    /// let v = AttributeValue::from_str(ElementId::Path, "", AttributeId::Marker, "url(#m)").unwrap();
+    /// assert_eq!(v.expand_marker_shorthand(), [
+    ///     (AttributeId::MarkerStart, AttributeValue::FuncIRI(FuncIRI::local("m"))),
+    ///     (AttributeId::MarkerMid, AttributeValue::FuncIRI(FuncIRI::local("m"))),
+    ///     (AttributeId::MarkerEnd, AttributeValue::FuncIRI(FuncIRI::local("m"))),
+    /// ]);
+    /// ```
+    ///
+    /// [`marker`]: https://www.w3.org/TR/SVG11/painting.html#MarkerProperty
+    pub fn expand_marker_shorthand(&self) -> [(AttributeId, AttributeValue<'a>); 3] {
+        [
+            (AttributeId::MarkerStart, *self),
+            (AttributeId::MarkerMid, *self),
+            (AttributeId::MarkerEnd, *self),
+        ]
+    }
 }
 
 macro_rules! parse_or {
@@ -189,9 +684,12 @@ fn parse_av<'a>(
     prefix: &str,
     aid: AttributeId,
     span: StrSpan<'a>,
+    strict: bool,
 ) -> StreamResult<AttributeValue<'a>> {
     use AttributeId as AId;
 
+    let original = span;
+
     // 'unicode' attribute can contain spaces
     let span = if aid != AId::Unicode { span.trim() } else { span };
 
@@ -215,20 +713,43 @@ fn parse_av<'a>(
     }
 
     if stream.is_curr_byte_eq(b'&') {
-        // TODO: attribute can contain many refs, not only one
-        // TODO: advance to the end of the stream
-        let r = stream.consume_reference();
-        if let Ok(Reference::EntityRef(name)) = r {
-            return Ok(AttributeValue::EntityRef(name.to_str()));
+        let mut s = stream;
+        if let Ok(Reference::EntityRef(name)) = s.consume_reference() {
+            if s.at_end() {
+                return Ok(AttributeValue::EntityRef(name.to_str()));
+            }
         }
     }
 
+    // A value with entity references can't be parsed before they are resolved,
+    // so it should be returned as is.
+    if has_entity_ref(span) {
+        return Ok(AttributeValue::String(span.to_str()));
+    }
+
     if aid == AId::Href && prefix == "xlink" {
         return parse_iri(stream);
     }
 
+    if aid == AId::Space && prefix == "xml" {
+        return match span.to_str() {
+            "default" => Ok(AttributeValue::XmlSpace(XmlSpace::Default)),
+            "preserve" => Ok(AttributeValue::XmlSpace(XmlSpace::Preserve)),
+            name => Err(StreamError::InvalidPredefValue(name.into())),
+        };
+    }
+
+    // Attributes with an unknown prefix are preserved as is.
     if !prefix.is_empty() {
-        return Ok(AttributeValue::String(stream.span().to_str()));
+        return Ok(AttributeValue::String(original.to_str()));
+    }
+
+    if is_presentation_attribute(eid, aid) {
+        // CSS-wide keywords can't be combined with other values,
+        // so the whole attribute value must be a keyword.
+        if let Some(k) = CssWideKeyword::from_name(stream.span().to_str()) {
+            return Ok(AttributeValue::CssWide(k));
+        }
     }
 
     match aid {
@@ -257,6 +778,12 @@ fn parse_av<'a>(
         | AId::Fx | AId::Fy
         | AId::Offset
         | AId::Width | AId::Height => {
+            if aid == AId::Offset && eid == ElementId::Stop {
+                // A gradient stop offset is a number or a percentage bound to 0..1.
+                let n = stream.parse_opacity()?;
+                return Ok(AttributeValue::Number(n));
+            }
+
             let l = stream.parse_length()?;
             Ok(AttributeValue::Length(l))
         }
@@ -273,8 +800,7 @@ fn parse_av<'a>(
         | AId::StrokeOpacity
         | AId::StopOpacity => {
             fn get_opacity<'a>(mut s: Stream) -> StreamResult<AttributeValue<'a>> {
-                let mut n = s.parse_number()?;
-                n = f64_bound(0.0, n, 1.0);
+                let n = s.parse_opacity()?;
                 Ok(AttributeValue::Number(n))
             }
 
@@ -303,7 +829,7 @@ fn parse_av<'a>(
                             ValueId::None,
                             ValueId::CurrentColor,
                             ValueId::Inherit),
-                        parse_or!(parse_paint_func_iri(stream), parse_rgb_color(stream)))
+                        parse_paint(stream))
                 }
             }
         }
@@ -313,7 +839,7 @@ fn parse_av<'a>(
                     ValueId::None,
                     ValueId::CurrentColor,
                     ValueId::Inherit),
-                parse_or!(parse_paint_func_iri(stream), parse_rgb_color(stream)))
+                parse_paint(stream))
         }
 
           AId::ClipPath
@@ -343,8 +869,7 @@ fn parse_av<'a>(
 
           AId::StdDeviation
         | AId::BaseFrequency => {
-            // TODO: this attributes can contain only one or two numbers
-            Ok(AttributeValue::NumberList(NumberList::from_span(span)))
+            parse_number_opt_number(stream)
         }
 
         AId::Points => {
@@ -352,6 +877,11 @@ fn parse_av<'a>(
         }
 
         AId::D => {
+            parse_or!(parse_predef!(ValueId::None), parse_path(stream))
+        }
+
+        // Only 'animateMotion' has the 'path' attribute with a path data.
+        AId::Path if eid == ElementId::AnimateMotion => {
             Ok(AttributeValue::Path(path::Tokenizer::from_span(span)))
         }
 
@@ -735,66 +1265,346 @@ fn parse_av<'a>(
         }
 
         AId::ViewBox => {
-            parse_view_box(stream)
+            Ok(AttributeValue::ViewBox(ViewBox::from_span(span)?))
+        }
+
+        AId::Orient => {
+            let orient = match span.to_str() {
+                "auto" => Orient::Auto,
+                "auto-start-reverse" => Orient::AutoStartReverse,
+                _ => Orient::Angle(parse_angle(stream)?),
+            };
+
+            Ok(AttributeValue::Orient(orient))
+        }
+
+        AId::ShapeInside => {
+            parse_or!(parse_predef!(ValueId::Auto, ValueId::None, ValueId::Inherit),
+                      Ok(AttributeValue::ShapeList(ShapeList::from_span(span))))
+        }
+
+        AId::Values if is_animation_element(eid) => {
+            Ok(AttributeValue::ValuesList(ValuesList::from_span(span)))
+        }
+
+        AId::NumOctaves => {
+            // A non-negative integer.
+            let start = stream.pos();
+            let n = stream.parse_integer()?;
+            stream.skip_spaces();
+            if n < 0 || !stream.at_end() {
+                return Err(StreamError::InvalidNumber(stream.gen_error_pos_from(start)));
+            }
+
+            Ok(AttributeValue::Number(n as f64))
+        }
+
+        AId::RepeatCount => {
+            // 'indefinite' or a positive number.
+            parse_or!(parse_predef!(ValueId::Indefinite), {
+                let start = stream.pos();
+                let n = stream.parse_number()?;
+                stream.skip_spaces();
+                if n <= 0.0 || !stream.at_end() {
+                    return Err(StreamError::InvalidNumber(stream.gen_error_pos_from(start)));
+                }
+
+                Ok(AttributeValue::Number(n))
+            })
+        }
+
+        AId::SystemLanguage => {
+            Ok(AttributeValue::CommaList(CommaList::from_span(span)))
+        }
+
+        AId::RequiredFeatures | AId::RequiredExtensions => {
+            Ok(AttributeValue::SpaceList(SpaceList::from_span(span)))
+        }
+
+        AId::In | AId::In2 => {
+            let text = stream.span().to_str();
+            if text.is_empty() {
+                return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
+            }
+
+            Ok(AttributeValue::FilterInput(FilterInput::from(text)))
+        }
+
+        AId::Dur => {
+            parse_or!(parse_predef!(ValueId::Media, ValueId::Indefinite),
+                      Ok(AttributeValue::ClockValue(ClockValue::from_span(span)?)))
+        }
+
+        AId::RepeatDur => {
+            parse_or!(parse_predef!(ValueId::Indefinite),
+                      Ok(AttributeValue::ClockValue(ClockValue::from_span(span)?)))
+        }
+
+        AId::KeyTimes => {
+            Ok(AttributeValue::KeyTimes(KeyTimes::from_span(span)))
+        }
+
+        AId::KeySplines => {
+            Ok(AttributeValue::KeySplines(KeySplines::from_span(span)))
+        }
+
+        AId::ShapeSubtract => {
+            parse_or!(parse_predef!(ValueId::None, ValueId::Inherit),
+                      Ok(AttributeValue::ShapeList(ShapeList::from_span(span))))
+        }
+
+        AId::Font => {
+            // A shorthand that we can't parse should be preserved as is.
+            parse_or!(parse_predef!(ValueId::Inherit),
+                match FontShorthand::from_span(span) {
+                    Ok(font) => Ok(AttributeValue::Font(font)),
+                    Err(_) => Ok(AttributeValue::String(span.to_str())),
+                })
+        }
+
+        AId::Clip => {
+            parse_or!(parse_predef!(ValueId::Auto, ValueId::Inherit),
+                      parse_clip_rect(stream))
         }
 
         AId::PreserveAspectRatio => {
             parse_aspect_ratio(stream)
         }
 
-        _ => Ok(AttributeValue::String(stream.span().to_str())),
+        _ => {
+            if strict {
+                Err(StreamError::UnknownAttributeValue(aid))
+            } else {
+                Ok(AttributeValue::String(stream.span().to_str()))
+            }
+        }
     }
 }
 
-fn parse_paint_func_iri<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
-    if !stream.at_end() && stream.curr_byte()? == b'u' {
-        stream.skip_string(b"url(#")?;
-        let link = stream.consume_name()?.to_str();
+fn is_animation_element(eid: ElementId) -> bool {
+    static LIST: &[ElementId] = &[
+        ElementId::Animate,
+        ElementId::AnimateColor,
+        ElementId::AnimateMotion,
+        ElementId::AnimateTransform,
+    ];
 
-        stream.consume_byte(b')')?;
-        stream.skip_spaces();
+    LIST.contains(&eid)
+}
 
-        // get fallback
-        if !stream.at_end() {
-            let fallback = stream.slice_tail();
+fn has_entity_ref(span: StrSpan) -> bool {
+    let mut s = Stream::from_span(span);
+    while !s.at_end() {
+        s.skip_bytes(|_, c| c != b'&');
+        if s.at_end() {
+            break;
+        }
 
-            let vid = match ValueId::from_name(fallback.to_str()) {
-                Some(v) => {
-                    match v {
-                          ValueId::None
-                        | ValueId::CurrentColor => Some(PaintFallback::PredefValue(v)),
-                        _ => None,
-                    }
-                }
-                None => None,
-            };
+        let mut s2 = s;
+        if let Ok(Reference::EntityRef(_)) = s2.consume_reference() {
+            return true;
+        }
 
-            if let Some(v) = vid {
-                Ok(AttributeValue::FuncIRIWithFallback(link, v))
-            } else {
-                let color = Color::from_span(fallback)?;
-                Ok(AttributeValue::FuncIRIWithFallback(link, PaintFallback::Color(color)))
+        // Not a reference, or a character one.
+        s.advance(1);
+    }
+
+    false
+}
+
+fn is_presentation_attribute(eid: ElementId, aid: AttributeId) -> bool {
+    if aid == AttributeId::Fill {
+        // 'fill' in animate-based elements it's another 'fill'
+        return    eid != ElementId::Set
+               && eid != ElementId::Animate
+               && eid != ElementId::AnimateColor
+               && eid != ElementId::AnimateMotion
+               && eid != ElementId::AnimateTransform;
+    }
+
+    aid.is_presentation()
+}
+
+fn parse_clip_rect<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
+    // Offsets can be separated by commas or spaces.
+    fn parse_offset(s: &mut Stream) -> StreamResult<Option<Length>> {
+        s.skip_spaces();
+        if is_auto(s) {
+            s.advance(4);
+            s.skip_spaces();
+            if s.is_curr_byte_eq(b',') {
+                s.advance(1);
+            }
+
+            Ok(None)
+        } else {
+            Ok(Some(s.parse_list_length()?))
+        }
+    }
+
+    // `auto` followed by a separator, so `autofoo` is not matched.
+    fn is_auto(s: &Stream) -> bool {
+        if !s.starts_with(b"auto") {
+            return false;
+        }
+
+        let mut s2 = *s;
+        s2.advance(4);
+        match s2.get_curr_byte() {
+            Some(c) => c.is_xml_space() || c == b',' || c == b')',
+            None => true,
+        }
+    }
+
+    stream.skip_string(b"rect(")?;
+    let top = parse_offset(&mut stream)?;
+    let right = parse_offset(&mut stream)?;
+    let bottom = parse_offset(&mut stream)?;
+    let left = parse_offset(&mut stream)?;
+    stream.skip_spaces();
+    stream.consume_byte(b')')?;
+
+    stream.skip_spaces();
+    if !stream.at_end() {
+        let c = stream.curr_char()?;
+        let pos = stream.gen_error_pos();
+        return Err(xmlparser::StreamError::InvalidChar(c, "end of stream".into(), pos).into());
+    }
+
+    Ok(AttributeValue::ClipRect(ClipRect::new(top, right, bottom, left)))
+}
+
+fn parse_number_opt_number<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
+    let n1 = stream.parse_list_number()?;
+    // `parse_list_number` consumes a separator, so `1,` will be at the end too.
+    if stream.at_end() && !stream.span().trim().to_str().ends_with(',') {
+        return Ok(AttributeValue::NumberOptNumber(n1, n1));
+    }
+
+    let n2 = stream.parse_number()?;
+    stream.skip_spaces();
+    if !stream.at_end() {
+        return Err(StreamError::InvalidNumber(stream.gen_error_pos()));
+    }
+
+    Ok(AttributeValue::NumberOptNumber(n1, n2))
+}
+
+fn parse_paint<'a>(stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
+    if stream.starts_with(b"url(") {
+        parse_paint_func_iri(stream)
+    } else {
+        parse_rgb_color(stream)
+    }
+}
+
+fn parse_paint_func_iri<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
+    let link = parse_func_link(&mut stream)?;
+    stream.skip_spaces();
+
+    // get fallback
+    if !stream.at_end() {
+        let fallback = stream.slice_tail();
+
+        let vid = match ValueId::from_name(fallback.to_str()) {
+            Some(v) => {
+                match v {
+                      ValueId::None
+                    | ValueId::CurrentColor => Some(PaintFallback::PredefValue(v)),
+                    _ => None,
+                }
             }
+            None => None,
+        };
+
+        if let Some(v) = vid {
+            Ok(AttributeValue::FuncIRIWithFallback(link, v))
         } else {
-            Ok(AttributeValue::FuncIRI(link))
+            let color = Color::from_span(fallback)?;
+            Ok(AttributeValue::FuncIRIWithFallback(link, PaintFallback::Color(color)))
         }
     } else {
-        Err(StreamError::NotAFuncIRI(stream.span().to_str().into()))
+        Ok(AttributeValue::FuncIRI(link))
     }
 }
 
 fn parse_func_iri<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
-    if !stream.at_end() && stream.curr_byte()? == b'u' {
-        stream.skip_string(b"url(#")?;
-        let link = stream.consume_name()?.to_str();
+    let link = parse_func_link(&mut stream)?;
+    Ok(AttributeValue::FuncIRI(link))
+}
+
+// Parses `url(base#fragment)`.
+fn parse_func_link<'a>(stream: &mut Stream<'a>) -> StreamResult<FuncIRI<'a>> {
+    if !stream.starts_with(b"url(") {
+        return Err(StreamError::NotAFuncIRI(stream.span().to_str().into()));
+    }
+
+    stream.advance(4);
+    stream.skip_spaces();
+    let link = stream.consume_bytes(|_, c| c != b')' && !c.is_xml_space());
+    stream.skip_spaces();
+
+    if !stream.is_curr_byte_eq(b')') {
+        return Err(StreamError::InvalidFuncIRI(stream.gen_error_pos()));
+    }
+
+    let link = link.to_str();
+    let (base, fragment) = match link.find('#') {
+        Some(idx) => (&link[..idx], &link[idx + 1..]),
+        None => (link, ""),
+    };
+
+    let non_empty = |s: &'a str| if s.is_empty() { None } else { Some(s) };
+    let link = FuncIRI::new(non_empty(base), non_empty(fragment));
+    if link.base.is_none() && link.fragment.is_none() {
+        return Err(StreamError::InvalidFuncIRI(stream.gen_error_pos()));
+    }
+
+    stream.advance(1);
+
+    Ok(link)
+}
+
+fn parse_path<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
+    // SVG 2 allows 'd' to be set via CSS using the 'path("...")' function.
+    if stream.starts_with(b"path(") {
+        stream.advance(5);
+        stream.skip_spaces();
+        let quote = stream.consume_quote()?;
+        let data = stream.consume_bytes(|_, c| c != quote);
+        stream.consume_byte(quote)?;
+        stream.skip_spaces();
         stream.consume_byte(b')')?;
 
-        Ok(AttributeValue::FuncIRI(link))
+        stream.skip_spaces();
+        if !stream.at_end() {
+            let c = stream.curr_char()?;
+            let pos = stream.gen_error_pos();
+            return Err(xmlparser::StreamError::InvalidChar(c, "end of stream".into(), pos).into());
+        }
+
+        Ok(AttributeValue::Path(path::Tokenizer::from_span(data)))
     } else {
-        Err(StreamError::NotAFuncIRI(stream.span().to_str().into()))
+        Ok(AttributeValue::Path(path::Tokenizer::from_span(stream.span())))
     }
 }
 
+// angle ::= number ("deg" | "grad" | "rad")?
+//
+// The result is in degrees.
+fn parse_angle(mut s: Stream) -> StreamResult<f64> {
+    let n = s.parse_number()?;
+    let start = s.pos();
+    let n = match s.slice_tail().to_str() {
+        "" | "deg" => n,
+        "grad" => n * 0.9,
+        "rad" => n.to_degrees(),
+        _ => return Err(StreamError::InvalidAngle(s.gen_error_pos_from(start))),
+    };
+
+    Ok(n)
+}
+
 fn parse_iri<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
     // empty xlink:href is a valid attribute
     if !stream.at_end() && stream.curr_byte()? == b'#' {
@@ -827,26 +1637,3 @@ fn parse_aspect_ratio<'a>(stream: Stream<'a>) -> StreamResult<AttributeValue<'a>
     Ok(AttributeValue::AspectRatio(r))
 }
 
-fn parse_view_box<'a>(mut stream: Stream<'a>) -> StreamResult<AttributeValue<'a>> {
-    let x = stream.parse_list_number()?;
-    let y = stream.parse_list_number()?;
-    let w = stream.parse_list_number()?;
-    let h = stream.parse_list_number()?;
-
-    if w <= 0.0 || h <= 0.0 {
-        return Err(StreamError::InvalidViewbox);
-    }
-
-    Ok(AttributeValue::ViewBox(ViewBox::new(x, y, w, h)))
-}
-
-#[inline]
-fn f64_bound(min: f64, val: f64, max: f64) -> f64 {
-    if val > max {
-        return max;
-    } else if val < min {
-        return min;
-    }
-
-    val
-}
@@ -0,0 +1,315 @@
+// Copyright 2018 Evgeniy Reizner
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Element, attribute and value categories.
+//
+// Kept separately from the autogenerated ids.
+
+use {
+    AttributeId,
+    ElementId,
+    ValueId,
+};
+
+impl ElementId {
+    /// Checks that element is a [basic shape].
+    ///
+    /// [basic shape]: https://www.w3.org/TR/SVG11/intro.html#TermShape
+    pub fn is_shape(&self) -> bool {
+        match *self {
+              ElementId::Circle
+            | ElementId::Ellipse
+            | ElementId::Line
+            | ElementId::Path
+            | ElementId::Polygon
+            | ElementId::Polyline
+            | ElementId::Rect => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that element is a [graphics element].
+    ///
+    /// [graphics element]: https://www.w3.org/TR/SVG11/intro.html#TermGraphicsElement
+    pub fn is_graphic(&self) -> bool {
+        match *self {
+              ElementId::Circle
+            | ElementId::Ellipse
+            | ElementId::Image
+            | ElementId::Line
+            | ElementId::Path
+            | ElementId::Polygon
+            | ElementId::Polyline
+            | ElementId::Rect
+            | ElementId::Text
+            | ElementId::Use => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that element is a [gradient element].
+    ///
+    /// [gradient element]: https://www.w3.org/TR/SVG11/intro.html#TermGradientElement
+    pub fn is_gradient(&self) -> bool {
+        match *self {
+              ElementId::LinearGradient
+            | ElementId::RadialGradient => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that element is a [filter primitive element].
+    ///
+    /// Transfer functions, light sources and `feMergeNode` are not filter primitives.
+    ///
+    /// [filter primitive element]: https://www.w3.org/TR/SVG11/intro.html#TermFilterPrimitiveElement
+    pub fn is_filter_primitive(&self) -> bool {
+        match *self {
+              ElementId::FeBlend
+            | ElementId::FeColorMatrix
+            | ElementId::FeComponentTransfer
+            | ElementId::FeComposite
+            | ElementId::FeConvolveMatrix
+            | ElementId::FeDiffuseLighting
+            | ElementId::FeDisplacementMap
+            | ElementId::FeFlood
+            | ElementId::FeGaussianBlur
+            | ElementId::FeImage
+            | ElementId::FeMerge
+            | ElementId::FeMorphology
+            | ElementId::FeOffset
+            | ElementId::FeSpecularLighting
+            | ElementId::FeTile
+            | ElementId::FeTurbulence => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that element is a [text content element].
+    ///
+    /// [text content element]: https://www.w3.org/TR/SVG11/intro.html#TermTextContentElement
+    pub fn is_text_content(&self) -> bool {
+        match *self {
+              ElementId::AltGlyph
+            | ElementId::Text
+            | ElementId::TextPath
+            | ElementId::Tref
+            | ElementId::Tspan => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that element is a [container element].
+    ///
+    /// [container element]: https://www.w3.org/TR/SVG11/intro.html#TermContainerElement
+    pub fn is_container(&self) -> bool {
+        match *self {
+              ElementId::A
+            | ElementId::Defs
+            | ElementId::G
+            | ElementId::Glyph
+            | ElementId::Marker
+            | ElementId::Mask
+            | ElementId::MissingGlyph
+            | ElementId::Pattern
+            | ElementId::Svg
+            | ElementId::Switch
+            | ElementId::Symbol => true,
+            _ => false,
+        }
+    }
+}
+
+impl AttributeId {
+    /// Checks that attribute is a [presentation attribute].
+    ///
+    /// Note that `fill` is not a presentation attribute inside animation elements.
+    ///
+    /// [presentation attribute]: https://www.w3.org/TR/SVG11/propidx.html
+    pub fn is_presentation(&self) -> bool {
+        match *self {
+              AttributeId::AlignmentBaseline
+            | AttributeId::BaselineShift
+            | AttributeId::Clip
+            | AttributeId::ClipPath
+            | AttributeId::ClipRule
+            | AttributeId::Color
+            | AttributeId::ColorInterpolation
+            | AttributeId::ColorInterpolationFilters
+            | AttributeId::ColorProfile
+            | AttributeId::ColorRendering
+            | AttributeId::Cursor
+            | AttributeId::Direction
+            | AttributeId::Display
+            | AttributeId::DominantBaseline
+            | AttributeId::EnableBackground
+            | AttributeId::Fill
+            | AttributeId::FillOpacity
+            | AttributeId::FillRule
+            | AttributeId::Filter
+            | AttributeId::FloodColor
+            | AttributeId::FloodOpacity
+            | AttributeId::Font
+            | AttributeId::FontFamily
+            | AttributeId::FontSize
+            | AttributeId::FontSizeAdjust
+            | AttributeId::FontStretch
+            | AttributeId::FontStyle
+            | AttributeId::FontVariant
+            | AttributeId::FontWeight
+            | AttributeId::GlyphOrientationHorizontal
+            | AttributeId::GlyphOrientationVertical
+            | AttributeId::ImageRendering
+            | AttributeId::Kerning
+            | AttributeId::LetterSpacing
+            | AttributeId::LightingColor
+            | AttributeId::Marker
+            | AttributeId::MarkerEnd
+            | AttributeId::MarkerMid
+            | AttributeId::MarkerStart
+            | AttributeId::Mask
+            | AttributeId::Opacity
+            | AttributeId::Overflow
+            | AttributeId::PointerEvents
+            | AttributeId::ShapeRendering
+            | AttributeId::StopColor
+            | AttributeId::StopOpacity
+            | AttributeId::Stroke
+            | AttributeId::StrokeDasharray
+            | AttributeId::StrokeDashoffset
+            | AttributeId::StrokeLinecap
+            | AttributeId::StrokeLinejoin
+            | AttributeId::StrokeMiterlimit
+            | AttributeId::StrokeOpacity
+            | AttributeId::StrokeWidth
+            | AttributeId::TextAnchor
+            | AttributeId::TextDecoration
+            | AttributeId::TextRendering
+            | AttributeId::UnicodeBidi
+            | AttributeId::Visibility
+            | AttributeId::WordSpacing
+            | AttributeId::WritingMode => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that attribute is an [event attribute], like `onclick`.
+    ///
+    /// [event attribute]: https://www.w3.org/TR/SVG11/script.html#EventAttributes
+    pub fn is_event(&self) -> bool {
+        match *self {
+              AttributeId::Onabort
+            | AttributeId::Onactivate
+            | AttributeId::Onbegin
+            | AttributeId::Onclick
+            | AttributeId::Onend
+            | AttributeId::Onerror
+            | AttributeId::Onfocusin
+            | AttributeId::Onfocusout
+            | AttributeId::Onload
+            | AttributeId::Onmousedown
+            | AttributeId::Onmousemove
+            | AttributeId::Onmouseout
+            | AttributeId::Onmouseover
+            | AttributeId::Onmouseup
+            | AttributeId::Onrepeat
+            | AttributeId::Onresize
+            | AttributeId::Onscroll
+            | AttributeId::Onunload
+            | AttributeId::Onzoom => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that attribute is an inheritable presentation attribute.
+    ///
+    /// Based on the [property index].
+    ///
+    /// [property index]: https://www.w3.org/TR/SVG11/propidx.html
+    pub fn is_inheritable(&self) -> bool {
+        match *self {
+              AttributeId::ClipRule
+            | AttributeId::Color
+            | AttributeId::ColorInterpolation
+            | AttributeId::ColorInterpolationFilters
+            | AttributeId::ColorProfile
+            | AttributeId::ColorRendering
+            | AttributeId::Cursor
+            | AttributeId::Direction
+            | AttributeId::Fill
+            | AttributeId::FillOpacity
+            | AttributeId::FillRule
+            | AttributeId::Font
+            | AttributeId::FontFamily
+            | AttributeId::FontSize
+            | AttributeId::FontSizeAdjust
+            | AttributeId::FontStretch
+            | AttributeId::FontStyle
+            | AttributeId::FontVariant
+            | AttributeId::FontWeight
+            | AttributeId::GlyphOrientationHorizontal
+            | AttributeId::GlyphOrientationVertical
+            | AttributeId::ImageRendering
+            | AttributeId::Kerning
+            | AttributeId::LetterSpacing
+            | AttributeId::Marker
+            | AttributeId::MarkerEnd
+            | AttributeId::MarkerMid
+            | AttributeId::MarkerStart
+            | AttributeId::PointerEvents
+            | AttributeId::ShapeRendering
+            | AttributeId::Stroke
+            | AttributeId::StrokeDasharray
+            | AttributeId::StrokeDashoffset
+            | AttributeId::StrokeLinecap
+            | AttributeId::StrokeLinejoin
+            | AttributeId::StrokeMiterlimit
+            | AttributeId::StrokeOpacity
+            | AttributeId::StrokeWidth
+            | AttributeId::TextAnchor
+            | AttributeId::TextRendering
+            | AttributeId::Visibility
+            | AttributeId::WordSpacing
+            | AttributeId::WritingMode => true,
+            _ => false,
+        }
+    }
+}
+
+impl ValueId {
+    /// Checks that value is allowed in any presentation attribute.
+    ///
+    /// Only `inherit` is such value in SVG 1.1.
+    /// Other CSS-wide keywords are parsed as `CssWideKeyword`.
+    pub fn is_global(&self) -> bool {
+        *self == ValueId::Inherit
+    }
+
+    /// Checks that value is a non-color [`<paint>`] keyword: `none` or `currentColor`.
+    ///
+    /// [`<paint>`]: https://www.w3.org/TR/SVG11/painting.html#SpecifyingPaint
+    pub fn is_paint_keyword(&self) -> bool {
+        match *self {
+              ValueId::None
+            | ValueId::CurrentColor => true,
+            _ => false,
+        }
+    }
+
+    /// Checks that value is a color keyword.
+    ///
+    /// Named colors, like `red`, are parsed as `Color`, so only `currentColor` is left.
+    pub fn is_color_keyword(&self) -> bool {
+        *self == ValueId::CurrentColor
+    }
+
+    /// Checks that value is `auto`.
+    pub fn is_auto(&self) -> bool {
+        *self == ValueId::Auto
+    }
+}
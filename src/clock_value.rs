@@ -0,0 +1,155 @@
+// Copyright 2018 Evgeniy Reizner
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::str::FromStr;
+
+use xmlparser::{
+    Stream,
+    StrSpan,
+    XmlByteExt,
+};
+
+use error::{
+    StreamError,
+    StreamResult,
+};
+use StreamExt;
+
+/// Representation of the SMIL [`<clock-value>`] type.
+///
+/// [`<clock-value>`]: https://www.w3.org/TR/SVG11/animate.html#ClockValueSyntax
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClockValue {
+    /// Duration in seconds.
+    pub seconds: f64,
+}
+
+impl ClockValue {
+    /// Constructs a new clock value.
+    pub fn new(seconds: f64) -> ClockValue {
+        ClockValue { seconds }
+    }
+
+    /// Parses `ClockValue` from `StrSpan`.
+    ///
+    /// Parsing is done according to:
+    ///
+    /// ```text
+    /// Clock-val         ::= Full-clock-val | Partial-clock-val | Timecount-val
+    /// Full-clock-val    ::= Hours ":" Minutes ":" Seconds ("." Fraction)?
+    /// Partial-clock-val ::= Minutes ":" Seconds ("." Fraction)?
+    /// Timecount-val     ::= Timecount ("." Fraction)? (Metric)?
+    /// Metric            ::= "h" | "min" | "s" | "ms"
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidClockValue` on an invalid value or when a value is followed by any data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::ClockValue;
+    /// use svgparser::xmlparser::StrSpan;
+    ///
+    /// assert_eq!(ClockValue::from_span(StrSpan::from_str("2s")).unwrap().seconds, 2.0);
+    /// assert_eq!(ClockValue::from_span(StrSpan::from_str("300ms")).unwrap().seconds, 0.3);
+    /// assert_eq!(ClockValue::from_span(StrSpan::from_str("02:30")).unwrap().seconds, 150.0);
+    /// ```
+    pub fn from_span(span: StrSpan) -> StreamResult<ClockValue> {
+        let mut s = Stream::from_span(span);
+        s.skip_spaces();
+        let start = s.pos();
+        let value = parse_clock_value(&mut s);
+        s.skip_spaces();
+
+        match value {
+            Some(seconds) if s.at_end() => Ok(ClockValue::new(seconds)),
+            _ => Err(StreamError::InvalidClockValue(s.gen_error_pos_from(start))),
+        }
+    }
+}
+
+impl FromStr for ClockValue {
+    type Err = StreamError;
+
+    fn from_str(text: &str) -> StreamResult<Self> {
+        ClockValue::from_span(StrSpan::from_str(text))
+    }
+}
+
+fn parse_clock_value(s: &mut Stream) -> Option<f64> {
+    let first = try_opt!(parse_decimal(s));
+    if s.consume_byte(b':').is_err() {
+        let metric = s.consume_bytes(|_, c| c.is_xml_letter());
+        let n = first.value;
+        return match metric.to_str() {
+            "h" => Some(n * 3600.0),
+            "min" => Some(n * 60.0),
+            "s" | "" => Some(n),
+            "ms" => Some(n / 1000.0),
+            _ => None,
+        };
+    }
+
+    let second = try_opt!(parse_decimal(s));
+    let (hours, minutes, seconds) = if s.consume_byte(b':').is_ok() {
+        (first, second, try_opt!(parse_decimal(s)))
+    } else {
+        (Decimal { value: 0.0, digits: 1, fraction: false }, first, second)
+    };
+
+    if hours.fraction || minutes.fraction || minutes.digits != 2 {
+        return None;
+    }
+
+    // Seconds are always two digits with an optional fraction.
+    if seconds.digits != 2 {
+        return None;
+    }
+
+    if minutes.value >= 60.0 || seconds.value >= 60.0 {
+        return None;
+    }
+
+    Some(hours.value * 3600.0 + minutes.value * 60.0 + seconds.value)
+}
+
+struct Decimal {
+    value: f64,
+    // The number of digits before the dot.
+    digits: usize,
+    fraction: bool,
+}
+
+// DIGIT+ ("." DIGIT+)?
+fn parse_decimal(s: &mut Stream) -> Option<Decimal> {
+    // `parse_number` also accepts a sign, a leading dot and an exponent,
+    // which are not allowed here.
+    if !s.get_curr_byte().map(|c| c.is_xml_digit()).unwrap_or(false) {
+        return None;
+    }
+
+    let start = s.pos();
+    let value = try_opt!(s.parse_number().ok());
+    let text = s.slice_back(start).to_str();
+    let digits = text.bytes().take_while(|c| c.is_xml_digit()).count();
+    let fraction = match &text[digits..] {
+        "" => false,
+        rest => {
+            let frac = &rest[1..];
+            if !rest.starts_with('.') || frac.is_empty() || !frac.bytes().all(|c| c.is_xml_digit()) {
+                return None;
+            }
+
+            true
+        }
+    };
+
+    Some(Decimal { value, digits, fraction })
+}
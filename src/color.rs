@@ -6,7 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::str::FromStr;
+use std::fmt;
+use std::str::{self, FromStr};
 use std::cmp;
 
 use xmlparser::{
@@ -16,10 +17,12 @@ use xmlparser::{
 };
 
 use error::{
+    ErrorSpan,
     StreamError,
     StreamResult,
 };
 use {
+    f64_bound,
     LengthUnit,
     StreamExt,
 };
@@ -27,6 +30,9 @@ use colors;
 
 /// Representation of the [`<color>`] type.
 ///
+/// The `alpha` channel is set only by the `transparent` keyword and the CSS Color 4
+/// `rgb(r g b / alpha)` syntax, since SVG 1.1 doesn't support colors with an alpha.
+///
 /// [`<color>`]: https://www.w3.org/TR/SVG/types.html#DataTypeColor
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[allow(missing_docs)]
@@ -34,13 +40,197 @@ pub struct Color {
     pub red: u8,
     pub green: u8,
     pub blue: u8,
+    pub alpha: u8,
 }
 
 impl Color {
-    /// Constructs a new `Color` from `red`, `green` and `blue` values.
+    /// The `black` color.
+    pub const BLACK: Color = Color { red: 0, green: 0, blue: 0, alpha: 255 };
+    /// The `white` color.
+    pub const WHITE: Color = Color { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// The `red` color.
+    pub const RED: Color = Color { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// The `lime` color.
+    pub const LIME: Color = Color { red: 0, green: 255, blue: 0, alpha: 255 };
+    /// The `blue` color.
+    pub const BLUE: Color = Color { red: 0, green: 0, blue: 255, alpha: 255 };
+    /// The `transparent` color.
+    pub const TRANSPARENT: Color = Color { red: 0, green: 0, blue: 0, alpha: 0 };
+
+    /// Constructs a new opaque `Color` from `red`, `green` and `blue` values.
     #[inline]
     pub fn new(red: u8, green: u8, blue: u8) -> Color {
-        Color { red, green, blue }
+        Color::new_rgba(red, green, blue, 255)
+    }
+
+    /// Constructs a new `Color` from `red`, `green`, `blue` and `alpha` values.
+    #[inline]
+    pub fn new_rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+        Color { red, green, blue, alpha }
+    }
+
+    /// Constructs a new `Color` from `hue`, `saturation` and `value` values.
+    ///
+    /// `hue` is in degrees and will be wrapped into the 0..360 range.
+    /// `saturation` and `value` will be bound to the 0..1 range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::new(255, 0, 0));
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 0.5), Color::new(0, 128, 0));
+    /// ```
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Color {
+        let mut h = hue % 360.0;
+        if h < 0.0 {
+            h += 360.0;
+        }
+
+        let s = f64_bound(0.0, saturation, 1.0);
+        let v = f64_bound(0.0, value, 1.0);
+
+        let c = v * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match (h / 60.0) as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        fn to_channel(n: f64) -> u8 {
+            f64_bound(0.0, (n * 255.0).round(), 255.0) as u8
+        }
+
+        Color::new(to_channel(r + m), to_channel(g + m), to_channel(b + m))
+    }
+
+    /// Converts a percentage into a color channel value.
+    ///
+    /// The result is rounded and bound to the 0..255 range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    ///
+    /// assert_eq!(Color::channel_from_percent(50.0), 128);
+    /// assert_eq!(Color::channel_from_percent(150.0), 255);
+    /// ```
+    pub fn channel_from_percent(value: f64) -> u8 {
+        // Multiply first, since `255.0 / 100.0` is not exact and 50% will become 127.
+        let n = (value * 255.0 / 100.0).round() as i32;
+        bound(0, n, 255) as u8
+    }
+
+    /// Converts the color into a gray one.
+    ///
+    /// Uses the Rec. 709 luminance: `0.2126 * R + 0.7152 * G + 0.0722 * B`.
+    /// The result is rounded. Alpha is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    ///
+    /// assert_eq!(Color::new(255, 0, 0).to_grayscale(), Color::new(54, 54, 54));
+    /// ```
+    pub fn to_grayscale(self) -> Color {
+        let l =   0.2126 * self.red as f64
+                + 0.7152 * self.green as f64
+                + 0.0722 * self.blue as f64;
+        let l = bound(0, l.round() as i32, 255) as u8;
+        Color::new_rgba(l, l, l, self.alpha)
+    }
+
+    /// Composites the color over the `bg` color using the source-over operator.
+    ///
+    /// Channels are not premultiplied. The result is rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    ///
+    /// let c = Color::new_rgba(255, 0, 0, 128).over(Color::WHITE);
+    /// assert_eq!(c, Color::new(255, 127, 127));
+    /// ```
+    pub fn over(self, bg: Color) -> Color {
+        let sa = self.alpha as f64 / 255.0;
+        let ba = bg.alpha as f64 / 255.0;
+        let a = sa + ba * (1.0 - sa);
+        if a == 0.0 {
+            return Color::TRANSPARENT;
+        }
+
+        let blend = |s: u8, b: u8| {
+            let n = (s as f64 * sa + b as f64 * ba * (1.0 - sa)) / a;
+            bound(0, n.round() as i32, 255) as u8
+        };
+
+        Color::new_rgba(
+            blend(self.red, bg.red),
+            blend(self.green, bg.green),
+            blend(self.blue, bg.blue),
+            bound(0, (a * 255.0).round() as i32, 255) as u8,
+        )
+    }
+
+    /// Linearly interpolates between two colors.
+    ///
+    /// `t` will be bound to the 0..1 range. All channels, including alpha,
+    /// are interpolated. The result is rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    ///
+    /// assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color::new(128, 128, 128));
+    /// ```
+    pub fn lerp(self, other: Color, t: f64) -> Color {
+        let t = f64_bound(0.0, t, 1.0);
+        let mix = |a: u8, b: u8| {
+            let n = a as f64 + (b as f64 - a as f64) * t;
+            bound(0, n.round() as i32, 255) as u8
+        };
+
+        Color::new_rgba(
+            mix(self.red, other.red),
+            mix(self.green, other.green),
+            mix(self.blue, other.blue),
+            mix(self.alpha, other.alpha),
+        )
+    }
+
+    /// Returns a [color keyword] name of the color.
+    ///
+    /// Returns `None` if there is no such keyword or the color is not opaque.
+    /// If a color has multiple names, like `gray` and `grey`, the shortest one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    ///
+    /// assert_eq!(Color::new(255, 0, 0).to_name(), Some("red"));
+    /// assert_eq!(Color::new(128, 128, 128).to_name(), Some("gray"));
+    /// assert_eq!(Color::new(1, 2, 3).to_name(), None);
+    /// ```
+    ///
+    /// [color keyword]: https://www.w3.org/TR/SVG/types.html#ColorKeywords
+    pub fn to_name(self) -> Option<&'static str> {
+        if self.alpha != 255 {
+            return None;
+        }
+
+        colors::rgb_color_to_name(self.red, self.green, self.blue)
     }
 
     /// Parses `Color` from `StrSpan`.
@@ -51,8 +241,11 @@ impl Color {
     /// color    ::= "#" hexdigit hexdigit hexdigit (hexdigit hexdigit hexdigit)?
     ///              | "rgb(" wsp* integer comma integer comma integer wsp* ")"
     ///              | "rgb(" wsp* integer "%" comma integer "%" comma integer "%" wsp* ")"
+    ///              | "rgb(" wsp* number wsp+ number wsp+ number (wsp* "/" wsp* alpha)? wsp* ")"
     ///              | color-keyword
+    ///              | "transparent"
     /// hexdigit ::= [0-9A-Fa-f]
+    /// alpha    ::= number | number "%"
     /// comma    ::= wsp* "," wsp*
     /// ```
     /// \* The SVG spec has an error. There should be `number`,
@@ -71,11 +264,42 @@ impl Color {
     /// # Notes
     ///
     ///  - Any non-`hexdigit` bytes will be treated as `0`.
-    ///  - Allocates heap memory for case-insensitive named colors comparison.
+    ///  - Like in browsers, non-percent `rgb()` values can be numbers,
+    ///    which will be rounded to the nearest integer.
+    ///  - `transparent` is parsed as a black color with a zero alpha.
+    ///  - The space-separated `rgb()` form with an optional `/ alpha`
+    ///    from CSS Color 4 is supported too. Alpha is bound to the 0..1 range.
+    ///  - `currentColor` is not a color and will produce an error.
+    ///    It's parsed as `ValueId::CurrentColor` by the `AttributeValue`.
     ///
     /// [spec]: http://www.w3.org/TR/SVG/types.html#DataTypeColor
     /// [details]: https://lists.w3.org/Archives/Public/www-svg/2014Jan/0109.html
     pub fn from_span(span: StrSpan) -> StreamResult<Color> {
+        Color::from_span_with_name(span).map(|v| v.0)
+    }
+
+    /// Parses `Color` from `StrSpan` and returns a canonical color keyword name,
+    /// when a color was set by name.
+    ///
+    /// Same as [`from_span`], but also returns a lowercase name of a color keyword.
+    /// Useful for serializers that prefer to preserve named colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Color;
+    /// use svgparser::xmlparser::StrSpan;
+    ///
+    /// let (c, name) = Color::from_span_with_name(StrSpan::from_str("RED")).unwrap();
+    /// assert_eq!(c, Color::new(255, 0, 0));
+    /// assert_eq!(name, Some("red"));
+    ///
+    /// let (_, name) = Color::from_span_with_name(StrSpan::from_str("#ff0000")).unwrap();
+    /// assert_eq!(name, None);
+    /// ```
+    ///
+    /// [`from_span`]: #method.from_span
+    pub fn from_span_with_name(span: StrSpan) -> StreamResult<(Color, Option<&'static str>)> {
         let mut s = Stream::from_span(span);
 
         s.skip_spaces();
@@ -83,6 +307,7 @@ impl Color {
         let start = s.pos();
 
         let mut color = Color::new(0, 0, 0);
+        let mut color_name = None;
 
         if s.curr_byte()? == b'#' {
             s.advance(1);
@@ -102,40 +327,109 @@ impl Color {
                     color.blue = short_hex(color_str[2]);
                 }
                 _ => {
-                    return Err(StreamError::InvalidColor(s.gen_error_pos_from(start)));
+                    let end = s.pos();
+                    return Err(StreamError::InvalidColorSpan(ErrorSpan::from_stream(&mut s, start, end)));
                 }
             }
         } else if is_rgb(&s) {
             s.advance(4);
 
-            let l = s.parse_list_length()?;
+            // The format is defined by the first component.
+            // All other components must have the same one.
+            let mut is_percent = None;
+            // Same for separators: either commas or spaces.
+            let mut is_comma_sep = false;
+            let mut channels = [0; 3];
+            for (i, channel) in channels.iter_mut().enumerate() {
+                s.skip_spaces();
+                if s.at_end() {
+                    return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
+                }
 
-            if l.unit == LengthUnit::Percent {
-                fn from_persent(v: f64) -> u8 {
-                    let d = 255.0 / 100.0;
-                    let n = (v * d).round() as i32;
-                    bound(0, n, 255) as u8
+                let c_start = s.pos();
+                let l = s.parse_length()?;
+                let c_end = s.pos();
+                s.skip_spaces();
+
+                let is_comma = s.is_curr_byte_eq(b',');
+                if i == 0 {
+                    is_comma_sep = is_comma;
+                }
+
+                // A comma is allowed only between components of the comma syntax.
+                if !s.at_end() && is_comma != (is_comma_sep && i < 2) {
+                    let pos = s.pos();
+                    let span = ErrorSpan::from_stream(&mut s, pos, pos);
+                    return Err(StreamError::InvalidColorSpan(span));
+                }
+
+                if is_comma {
+                    s.advance(1);
                 }
 
-                color.red = from_persent(l.num);
-                color.green = from_persent(s.parse_list_length()?.num);
-                color.blue = from_persent(s.parse_list_length()?.num);
-            } else {
-                color.red = bound(0, l.num as i32, 255) as u8;
-                color.green = bound(0, s.parse_list_integer()?, 255) as u8;
-                color.blue = bound(0, s.parse_list_integer()?, 255) as u8;
+                let percent = match l.unit {
+                    LengthUnit::Percent => Some(true),
+                    LengthUnit::None => Some(false),
+                    _ => None,
+                };
+
+                let valid = match (percent, is_percent) {
+                    (Some(v1), Some(v2)) => v1 == v2,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                };
+
+                if !valid {
+                    let span = ErrorSpan::from_stream(&mut s, c_start, c_end);
+                    return Err(StreamError::InvalidColorSpan(span));
+                }
+
+                let percent = percent == Some(true);
+                is_percent = Some(percent);
+
+                *channel = if percent {
+                    Color::channel_from_percent(l.num)
+                } else {
+                    bound(0, l.num.round() as i32, 255) as u8
+                };
+            }
+
+            color.red = channels[0];
+            color.green = channels[1];
+            color.blue = channels[2];
+
+            // CSS Color 4 alpha: `rgb(r g b / alpha)`.
+            // Not allowed in the comma syntax.
+            s.skip_spaces();
+            if s.is_curr_byte_eq(b'/') {
+                if is_comma_sep {
+                    let pos = s.pos();
+                    let span = ErrorSpan::from_stream(&mut s, pos, pos);
+                    return Err(StreamError::InvalidColorSpan(span));
+                }
+
+                s.advance(1);
+                s.skip_spaces();
+                let a = f64_bound(0.0, s.parse_number_or_percent()?, 1.0);
+                color.alpha = (a * 255.0).round() as u8;
             }
 
             s.skip_spaces();
             s.consume_byte(b')')?;
         } else {
-            let name = s.consume_name()?.to_str().to_lowercase();
-            match colors::rgb_color_from_name(&name) {
-                Some(c) => {
+            let name = s.consume_name()?.to_str();
+            match color_entry_from_name_ci(name) {
+                Some((n, c)) => {
                     color = c;
+                    color_name = Some(n);
+                }
+                None if is_transparent(name) => {
+                    color = Color::new_rgba(0, 0, 0, 0);
+                    color_name = Some("transparent");
                 }
                 None => {
-                    return Err(StreamError::InvalidColor(s.gen_error_pos_from(start)));
+                    let end = s.pos();
+                    return Err(StreamError::InvalidColorSpan(ErrorSpan::from_stream(&mut s, start, end)));
                 }
             }
         }
@@ -144,10 +438,12 @@ impl Color {
         // which is not supported.
         s.skip_spaces();
         if !s.at_end() {
-            return Err(StreamError::InvalidColor(s.gen_error_pos()));
+            let pos = s.pos();
+            let end = pos + s.slice_tail().trim().len();
+            return Err(StreamError::InvalidColorSpan(ErrorSpan::from_stream(&mut s, pos, end)));
         }
 
-        Ok(color)
+        Ok((color, color_name))
     }
 }
 
@@ -159,6 +455,70 @@ impl FromStr for Color {
     }
 }
 
+/// Writes an opaque color as `#rrggbb`, a fully transparent one as `transparent`
+/// and a color with a partial alpha as `rgb(r g b / a)`.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.alpha {
+            // SVG 1.1 doesn't support colors with an alpha,
+            // so any fully transparent color is just `transparent`.
+            0 => write!(f, "transparent"),
+            255 => write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue),
+            a => {
+                // Three digits are enough to restore the same channel value.
+                let a = (a as f64 / 255.0 * 1000.0).round() / 1000.0;
+                write!(f, "rgb({} {} {} / {})", self.red, self.green, self.blue, a)
+            }
+        }
+    }
+}
+
+/// Returns a color by a case-insensitive [color keyword] name.
+///
+/// Doesn't allocate.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::{rgb_color_from_name_ci, Color};
+///
+/// assert_eq!(rgb_color_from_name_ci("Red"), Some(Color::new(255, 0, 0)));
+/// assert_eq!(rgb_color_from_name_ci("qwe"), None);
+/// ```
+///
+/// [color keyword]: https://www.w3.org/TR/SVG/types.html#ColorKeywords
+pub fn rgb_color_from_name_ci(name: &str) -> Option<Color> {
+    let mut buf = [0u8; NAME_BUF_LEN];
+    let name = try_opt!(to_lowercase_name(name, &mut buf));
+    colors::rgb_color_from_name(name)
+}
+
+fn color_entry_from_name_ci(name: &str) -> Option<(&'static str, Color)> {
+    let mut buf = [0u8; NAME_BUF_LEN];
+    let name = try_opt!(to_lowercase_name(name, &mut buf));
+    colors::rgb_color_entry_from_name(name)
+}
+
+// The longest color name is 'lightgoldenrodyellow', which is 20 bytes long,
+// so a longer name can't be a color and there is no need to allocate.
+const NAME_BUF_LEN: usize = 24;
+
+fn to_lowercase_name<'a>(name: &str, buf: &'a mut [u8; NAME_BUF_LEN]) -> Option<&'a str> {
+    if name.len() > buf.len() {
+        return None;
+    }
+
+    #[allow(unused_imports)]
+    use std::ascii::AsciiExt;
+
+    let buf = &mut buf[..name.len()];
+    buf.copy_from_slice(name.as_bytes());
+    buf.make_ascii_lowercase();
+
+    // ASCII lowercasing doesn't break UTF-8, so this will not fail.
+    str::from_utf8(buf).ok()
+}
+
 #[inline]
 fn from_hex(c: u8) -> u8 {
     match c {
@@ -182,6 +542,13 @@ fn hex_pair(c1: u8, c2: u8) -> u8 {
     (h1 << 4) | h2
 }
 
+fn is_transparent(name: &str) -> bool {
+    #[allow(unused_imports)]
+    use std::ascii::AsciiExt;
+
+    name.eq_ignore_ascii_case("transparent")
+}
+
 fn is_rgb(s: &Stream) -> bool {
     let mut s = s.clone();
     let prefix = s.consume_bytes(|_, c| c != b'(').to_str();
@@ -45,156 +45,305 @@ static COLORS: ::phf::Map<&'static str, Color> = ::phf::Map {
         (19, 90),
     ]),
     entries: ::phf::Slice::Static(&[
-        ("darkslategray", Color{ red: 47, green: 79, blue: 79 }),
-        ("moccasin", Color{ red: 255, green: 228, blue: 181 }),
-        ("green", Color{ red: 0, green: 128, blue: 0 }),
-        ("palegoldenrod", Color{ red: 238, green: 232, blue: 170 }),
-        ("cadetblue", Color{ red: 95, green: 158, blue: 160 }),
-        ("darkblue", Color{ red: 0, green: 0, blue: 139 }),
-        ("fuchsia", Color{ red: 255, green: 0, blue: 255 }),
-        ("yellowgreen", Color{ red: 154, green: 205, blue: 50 }),
-        ("lightsalmon", Color{ red: 255, green: 160, blue: 122 }),
-        ("tomato", Color{ red: 255, green: 99, blue: 71 }),
-        ("beige", Color{ red: 245, green: 245, blue: 220 }),
-        ("crimson", Color{ red: 220, green: 20, blue: 60 }),
-        ("lightgrey", Color{ red: 211, green: 211, blue: 211 }),
-        ("darkorange", Color{ red: 255, green: 140, blue: 0 }),
-        ("gainsboro", Color{ red: 220, green: 220, blue: 220 }),
-        ("whitesmoke", Color{ red: 245, green: 245, blue: 245 }),
-        ("mintcream", Color{ red: 245, green: 255, blue: 250 }),
-        ("seagreen", Color{ red: 46, green: 139, blue: 87 }),
-        ("royalblue", Color{ red: 65, green: 105, blue: 225 }),
-        ("aqua", Color{ red: 0, green: 255, blue: 255 }),
-        ("burlywood", Color{ red: 222, green: 184, blue: 135 }),
-        ("antiquewhite", Color{ red: 250, green: 235, blue: 215 }),
-        ("navajowhite", Color{ red: 255, green: 222, blue: 173 }),
-        ("darkred", Color{ red: 139, green: 0, blue: 0 }),
-        ("darkgray", Color{ red: 169, green: 169, blue: 169 }),
-        ("navy", Color{ red: 0, green: 0, blue: 128 }),
-        ("black", Color{ red: 0, green: 0, blue: 0 }),
-        ("khaki", Color{ red: 240, green: 230, blue: 140 }),
-        ("ghostwhite", Color{ red: 248, green: 248, blue: 255 }),
-        ("mediumaquamarine", Color{ red: 102, green: 205, blue: 170 }),
-        ("lightgreen", Color{ red: 144, green: 238, blue: 144 }),
-        ("paleturquoise", Color{ red: 175, green: 238, blue: 238 }),
-        ("papayawhip", Color{ red: 255, green: 239, blue: 213 }),
-        ("lightslategrey", Color{ red: 119, green: 136, blue: 153 }),
-        ("linen", Color{ red: 250, green: 240, blue: 230 }),
-        ("dimgrey", Color{ red: 105, green: 105, blue: 105 }),
-        ("mediumspringgreen", Color{ red: 0, green: 250, blue: 154 }),
-        ("slateblue", Color{ red: 106, green: 90, blue: 205 }),
-        ("chocolate", Color{ red: 210, green: 105, blue: 30 }),
-        ("silver", Color{ red: 192, green: 192, blue: 192 }),
-        ("deepskyblue", Color{ red: 0, green: 191, blue: 255 }),
-        ("chartreuse", Color{ red: 127, green: 255, blue: 0 }),
-        ("mistyrose", Color{ red: 255, green: 228, blue: 225 }),
-        ("darkgreen", Color{ red: 0, green: 100, blue: 0 }),
-        ("mediumvioletred", Color{ red: 199, green: 21, blue: 133 }),
-        ("cornsilk", Color{ red: 255, green: 248, blue: 220 }),
-        ("limegreen", Color{ red: 50, green: 205, blue: 50 }),
-        ("darkviolet", Color{ red: 148, green: 0, blue: 211 }),
-        ("lavender", Color{ red: 230, green: 230, blue: 250 }),
-        ("firebrick", Color{ red: 178, green: 34, blue: 34 }),
-        ("azure", Color{ red: 240, green: 255, blue: 255 }),
-        ("lightcyan", Color{ red: 224, green: 255, blue: 255 }),
-        ("hotpink", Color{ red: 255, green: 105, blue: 180 }),
-        ("darkgoldenrod", Color{ red: 184, green: 134, blue: 11 }),
-        ("lemonchiffon", Color{ red: 255, green: 250, blue: 205 }),
-        ("honeydew", Color{ red: 240, green: 255, blue: 240 }),
-        ("steelblue", Color{ red: 70, green: 130, blue: 180 }),
-        ("mediumseagreen", Color{ red: 60, green: 179, blue: 113 }),
-        ("orange", Color{ red: 255, green: 165, blue: 0 }),
-        ("gray", Color{ red: 128, green: 128, blue: 128 }),
-        ("lavenderblush", Color{ red: 255, green: 240, blue: 245 }),
-        ("rosybrown", Color{ red: 188, green: 143, blue: 143 }),
-        ("palevioletred", Color{ red: 219, green: 112, blue: 147 }),
-        ("greenyellow", Color{ red: 173, green: 255, blue: 47 }),
-        ("mediumblue", Color{ red: 0, green: 0, blue: 205 }),
-        ("lightgray", Color{ red: 211, green: 211, blue: 211 }),
-        ("magenta", Color{ red: 255, green: 0, blue: 255 }),
-        ("darkslategrey", Color{ red: 47, green: 79, blue: 79 }),
-        ("indianred", Color{ red: 205, green: 92, blue: 92 }),
-        ("blue", Color{ red: 0, green: 0, blue: 255 }),
-        ("powderblue", Color{ red: 176, green: 224, blue: 230 }),
-        ("floralwhite", Color{ red: 255, green: 250, blue: 240 }),
-        ("lightseagreen", Color{ red: 32, green: 178, blue: 170 }),
-        ("brown", Color{ red: 165, green: 42, blue: 42 }),
-        ("lightblue", Color{ red: 173, green: 216, blue: 230 }),
-        ("olive", Color{ red: 128, green: 128, blue: 0 }),
-        ("deeppink", Color{ red: 255, green: 20, blue: 147 }),
-        ("midnightblue", Color{ red: 25, green: 25, blue: 112 }),
-        ("white", Color{ red: 255, green: 255, blue: 255 }),
-        ("cornflowerblue", Color{ red: 100, green: 149, blue: 237 }),
-        ("forestgreen", Color{ red: 34, green: 139, blue: 34 }),
-        ("lightyellow", Color{ red: 255, green: 255, blue: 224 }),
-        ("wheat", Color{ red: 245, green: 222, blue: 179 }),
-        ("purple", Color{ red: 128, green: 0, blue: 128 }),
-        ("mediumorchid", Color{ red: 186, green: 85, blue: 211 }),
-        ("darkolivegreen", Color{ red: 85, green: 107, blue: 47 }),
-        ("aquamarine", Color{ red: 127, green: 255, blue: 212 }),
-        ("pink", Color{ red: 255, green: 192, blue: 203 }),
-        ("lime", Color{ red: 0, green: 255, blue: 0 }),
-        ("dodgerblue", Color{ red: 30, green: 144, blue: 255 }),
-        ("springgreen", Color{ red: 0, green: 255, blue: 127 }),
-        ("blueviolet", Color{ red: 138, green: 43, blue: 226 }),
-        ("oldlace", Color{ red: 253, green: 245, blue: 230 }),
-        ("red", Color{ red: 255, green: 0, blue: 0 }),
-        ("sandybrown", Color{ red: 244, green: 164, blue: 96 }),
-        ("grey", Color{ red: 128, green: 128, blue: 128 }),
-        ("salmon", Color{ red: 250, green: 128, blue: 114 }),
-        ("lawngreen", Color{ red: 124, green: 252, blue: 0 }),
-        ("peachpuff", Color{ red: 255, green: 218, blue: 185 }),
-        ("darkorchid", Color{ red: 153, green: 50, blue: 204 }),
-        ("yellow", Color{ red: 255, green: 255, blue: 0 }),
-        ("darkgrey", Color{ red: 169, green: 169, blue: 169 }),
-        ("darkseagreen", Color{ red: 143, green: 188, blue: 143 }),
-        ("plum", Color{ red: 221, green: 160, blue: 221 }),
-        ("lightslategray", Color{ red: 119, green: 136, blue: 153 }),
-        ("aliceblue", Color{ red: 240, green: 248, blue: 255 }),
-        ("sienna", Color{ red: 160, green: 82, blue: 45 }),
-        ("seashell", Color{ red: 255, green: 245, blue: 238 }),
-        ("lightgoldenrodyellow", Color{ red: 250, green: 250, blue: 210 }),
-        ("peru", Color{ red: 205, green: 133, blue: 63 }),
-        ("darkmagenta", Color{ red: 139, green: 0, blue: 139 }),
-        ("saddlebrown", Color{ red: 139, green: 69, blue: 19 }),
-        ("orangered", Color{ red: 255, green: 69, blue: 0 }),
-        ("lightskyblue", Color{ red: 135, green: 206, blue: 250 }),
-        ("lightcoral", Color{ red: 240, green: 128, blue: 128 }),
-        ("snow", Color{ red: 255, green: 250, blue: 250 }),
-        ("mediumpurple", Color{ red: 147, green: 112, blue: 219 }),
-        ("skyblue", Color{ red: 135, green: 206, blue: 235 }),
-        ("goldenrod", Color{ red: 218, green: 165, blue: 32 }),
-        ("indigo", Color{ red: 75, green: 0, blue: 130 }),
-        ("mediumturquoise", Color{ red: 72, green: 209, blue: 204 }),
-        ("darksalmon", Color{ red: 233, green: 150, blue: 122 }),
-        ("olivedrab", Color{ red: 107, green: 142, blue: 35 }),
-        ("blanchedalmond", Color{ red: 255, green: 235, blue: 205 }),
-        ("turquoise", Color{ red: 64, green: 224, blue: 208 }),
-        ("darkslateblue", Color{ red: 72, green: 61, blue: 139 }),
-        ("darkturquoise", Color{ red: 0, green: 206, blue: 209 }),
-        ("thistle", Color{ red: 216, green: 191, blue: 216 }),
-        ("slategrey", Color{ red: 112, green: 128, blue: 144 }),
-        ("maroon", Color{ red: 128, green: 0, blue: 0 }),
-        ("palegreen", Color{ red: 152, green: 251, blue: 152 }),
-        ("darkcyan", Color{ red: 0, green: 139, blue: 139 }),
-        ("tan", Color{ red: 210, green: 180, blue: 140 }),
-        ("darkkhaki", Color{ red: 189, green: 183, blue: 107 }),
-        ("bisque", Color{ red: 255, green: 228, blue: 196 }),
-        ("teal", Color{ red: 0, green: 128, blue: 128 }),
-        ("lightpink", Color{ red: 255, green: 182, blue: 193 }),
-        ("slategray", Color{ red: 112, green: 128, blue: 144 }),
-        ("gold", Color{ red: 255, green: 215, blue: 0 }),
-        ("coral", Color{ red: 255, green: 127, blue: 80 }),
-        ("cyan", Color{ red: 0, green: 255, blue: 255 }),
-        ("orchid", Color{ red: 218, green: 112, blue: 214 }),
-        ("violet", Color{ red: 238, green: 130, blue: 238 }),
-        ("dimgray", Color{ red: 105, green: 105, blue: 105 }),
-        ("ivory", Color{ red: 255, green: 255, blue: 240 }),
-        ("lightsteelblue", Color{ red: 176, green: 196, blue: 222 }),
-        ("mediumslateblue", Color{ red: 123, green: 104, blue: 238 }),
+        ("darkslategray", Color{ red: 47, green: 79, blue: 79, alpha: 255 }),
+        ("moccasin", Color{ red: 255, green: 228, blue: 181, alpha: 255 }),
+        ("green", Color{ red: 0, green: 128, blue: 0, alpha: 255 }),
+        ("palegoldenrod", Color{ red: 238, green: 232, blue: 170, alpha: 255 }),
+        ("cadetblue", Color{ red: 95, green: 158, blue: 160, alpha: 255 }),
+        ("darkblue", Color{ red: 0, green: 0, blue: 139, alpha: 255 }),
+        ("fuchsia", Color{ red: 255, green: 0, blue: 255, alpha: 255 }),
+        ("yellowgreen", Color{ red: 154, green: 205, blue: 50, alpha: 255 }),
+        ("lightsalmon", Color{ red: 255, green: 160, blue: 122, alpha: 255 }),
+        ("tomato", Color{ red: 255, green: 99, blue: 71, alpha: 255 }),
+        ("beige", Color{ red: 245, green: 245, blue: 220, alpha: 255 }),
+        ("crimson", Color{ red: 220, green: 20, blue: 60, alpha: 255 }),
+        ("lightgrey", Color{ red: 211, green: 211, blue: 211, alpha: 255 }),
+        ("darkorange", Color{ red: 255, green: 140, blue: 0, alpha: 255 }),
+        ("gainsboro", Color{ red: 220, green: 220, blue: 220, alpha: 255 }),
+        ("whitesmoke", Color{ red: 245, green: 245, blue: 245, alpha: 255 }),
+        ("mintcream", Color{ red: 245, green: 255, blue: 250, alpha: 255 }),
+        ("seagreen", Color{ red: 46, green: 139, blue: 87, alpha: 255 }),
+        ("royalblue", Color{ red: 65, green: 105, blue: 225, alpha: 255 }),
+        ("aqua", Color{ red: 0, green: 255, blue: 255, alpha: 255 }),
+        ("burlywood", Color{ red: 222, green: 184, blue: 135, alpha: 255 }),
+        ("antiquewhite", Color{ red: 250, green: 235, blue: 215, alpha: 255 }),
+        ("navajowhite", Color{ red: 255, green: 222, blue: 173, alpha: 255 }),
+        ("darkred", Color{ red: 139, green: 0, blue: 0, alpha: 255 }),
+        ("darkgray", Color{ red: 169, green: 169, blue: 169, alpha: 255 }),
+        ("navy", Color{ red: 0, green: 0, blue: 128, alpha: 255 }),
+        ("black", Color{ red: 0, green: 0, blue: 0, alpha: 255 }),
+        ("khaki", Color{ red: 240, green: 230, blue: 140, alpha: 255 }),
+        ("ghostwhite", Color{ red: 248, green: 248, blue: 255, alpha: 255 }),
+        ("mediumaquamarine", Color{ red: 102, green: 205, blue: 170, alpha: 255 }),
+        ("lightgreen", Color{ red: 144, green: 238, blue: 144, alpha: 255 }),
+        ("paleturquoise", Color{ red: 175, green: 238, blue: 238, alpha: 255 }),
+        ("papayawhip", Color{ red: 255, green: 239, blue: 213, alpha: 255 }),
+        ("lightslategrey", Color{ red: 119, green: 136, blue: 153, alpha: 255 }),
+        ("linen", Color{ red: 250, green: 240, blue: 230, alpha: 255 }),
+        ("dimgrey", Color{ red: 105, green: 105, blue: 105, alpha: 255 }),
+        ("mediumspringgreen", Color{ red: 0, green: 250, blue: 154, alpha: 255 }),
+        ("slateblue", Color{ red: 106, green: 90, blue: 205, alpha: 255 }),
+        ("chocolate", Color{ red: 210, green: 105, blue: 30, alpha: 255 }),
+        ("silver", Color{ red: 192, green: 192, blue: 192, alpha: 255 }),
+        ("deepskyblue", Color{ red: 0, green: 191, blue: 255, alpha: 255 }),
+        ("chartreuse", Color{ red: 127, green: 255, blue: 0, alpha: 255 }),
+        ("mistyrose", Color{ red: 255, green: 228, blue: 225, alpha: 255 }),
+        ("darkgreen", Color{ red: 0, green: 100, blue: 0, alpha: 255 }),
+        ("mediumvioletred", Color{ red: 199, green: 21, blue: 133, alpha: 255 }),
+        ("cornsilk", Color{ red: 255, green: 248, blue: 220, alpha: 255 }),
+        ("limegreen", Color{ red: 50, green: 205, blue: 50, alpha: 255 }),
+        ("darkviolet", Color{ red: 148, green: 0, blue: 211, alpha: 255 }),
+        ("lavender", Color{ red: 230, green: 230, blue: 250, alpha: 255 }),
+        ("firebrick", Color{ red: 178, green: 34, blue: 34, alpha: 255 }),
+        ("azure", Color{ red: 240, green: 255, blue: 255, alpha: 255 }),
+        ("lightcyan", Color{ red: 224, green: 255, blue: 255, alpha: 255 }),
+        ("hotpink", Color{ red: 255, green: 105, blue: 180, alpha: 255 }),
+        ("darkgoldenrod", Color{ red: 184, green: 134, blue: 11, alpha: 255 }),
+        ("lemonchiffon", Color{ red: 255, green: 250, blue: 205, alpha: 255 }),
+        ("honeydew", Color{ red: 240, green: 255, blue: 240, alpha: 255 }),
+        ("steelblue", Color{ red: 70, green: 130, blue: 180, alpha: 255 }),
+        ("mediumseagreen", Color{ red: 60, green: 179, blue: 113, alpha: 255 }),
+        ("orange", Color{ red: 255, green: 165, blue: 0, alpha: 255 }),
+        ("gray", Color{ red: 128, green: 128, blue: 128, alpha: 255 }),
+        ("lavenderblush", Color{ red: 255, green: 240, blue: 245, alpha: 255 }),
+        ("rosybrown", Color{ red: 188, green: 143, blue: 143, alpha: 255 }),
+        ("palevioletred", Color{ red: 219, green: 112, blue: 147, alpha: 255 }),
+        ("greenyellow", Color{ red: 173, green: 255, blue: 47, alpha: 255 }),
+        ("mediumblue", Color{ red: 0, green: 0, blue: 205, alpha: 255 }),
+        ("lightgray", Color{ red: 211, green: 211, blue: 211, alpha: 255 }),
+        ("magenta", Color{ red: 255, green: 0, blue: 255, alpha: 255 }),
+        ("darkslategrey", Color{ red: 47, green: 79, blue: 79, alpha: 255 }),
+        ("indianred", Color{ red: 205, green: 92, blue: 92, alpha: 255 }),
+        ("blue", Color{ red: 0, green: 0, blue: 255, alpha: 255 }),
+        ("powderblue", Color{ red: 176, green: 224, blue: 230, alpha: 255 }),
+        ("floralwhite", Color{ red: 255, green: 250, blue: 240, alpha: 255 }),
+        ("lightseagreen", Color{ red: 32, green: 178, blue: 170, alpha: 255 }),
+        ("brown", Color{ red: 165, green: 42, blue: 42, alpha: 255 }),
+        ("lightblue", Color{ red: 173, green: 216, blue: 230, alpha: 255 }),
+        ("olive", Color{ red: 128, green: 128, blue: 0, alpha: 255 }),
+        ("deeppink", Color{ red: 255, green: 20, blue: 147, alpha: 255 }),
+        ("midnightblue", Color{ red: 25, green: 25, blue: 112, alpha: 255 }),
+        ("white", Color{ red: 255, green: 255, blue: 255, alpha: 255 }),
+        ("cornflowerblue", Color{ red: 100, green: 149, blue: 237, alpha: 255 }),
+        ("forestgreen", Color{ red: 34, green: 139, blue: 34, alpha: 255 }),
+        ("lightyellow", Color{ red: 255, green: 255, blue: 224, alpha: 255 }),
+        ("wheat", Color{ red: 245, green: 222, blue: 179, alpha: 255 }),
+        ("purple", Color{ red: 128, green: 0, blue: 128, alpha: 255 }),
+        ("mediumorchid", Color{ red: 186, green: 85, blue: 211, alpha: 255 }),
+        ("darkolivegreen", Color{ red: 85, green: 107, blue: 47, alpha: 255 }),
+        ("aquamarine", Color{ red: 127, green: 255, blue: 212, alpha: 255 }),
+        ("pink", Color{ red: 255, green: 192, blue: 203, alpha: 255 }),
+        ("lime", Color{ red: 0, green: 255, blue: 0, alpha: 255 }),
+        ("dodgerblue", Color{ red: 30, green: 144, blue: 255, alpha: 255 }),
+        ("springgreen", Color{ red: 0, green: 255, blue: 127, alpha: 255 }),
+        ("blueviolet", Color{ red: 138, green: 43, blue: 226, alpha: 255 }),
+        ("oldlace", Color{ red: 253, green: 245, blue: 230, alpha: 255 }),
+        ("red", Color{ red: 255, green: 0, blue: 0, alpha: 255 }),
+        ("sandybrown", Color{ red: 244, green: 164, blue: 96, alpha: 255 }),
+        ("grey", Color{ red: 128, green: 128, blue: 128, alpha: 255 }),
+        ("salmon", Color{ red: 250, green: 128, blue: 114, alpha: 255 }),
+        ("lawngreen", Color{ red: 124, green: 252, blue: 0, alpha: 255 }),
+        ("peachpuff", Color{ red: 255, green: 218, blue: 185, alpha: 255 }),
+        ("darkorchid", Color{ red: 153, green: 50, blue: 204, alpha: 255 }),
+        ("yellow", Color{ red: 255, green: 255, blue: 0, alpha: 255 }),
+        ("darkgrey", Color{ red: 169, green: 169, blue: 169, alpha: 255 }),
+        ("darkseagreen", Color{ red: 143, green: 188, blue: 143, alpha: 255 }),
+        ("plum", Color{ red: 221, green: 160, blue: 221, alpha: 255 }),
+        ("lightslategray", Color{ red: 119, green: 136, blue: 153, alpha: 255 }),
+        ("aliceblue", Color{ red: 240, green: 248, blue: 255, alpha: 255 }),
+        ("sienna", Color{ red: 160, green: 82, blue: 45, alpha: 255 }),
+        ("seashell", Color{ red: 255, green: 245, blue: 238, alpha: 255 }),
+        ("lightgoldenrodyellow", Color{ red: 250, green: 250, blue: 210, alpha: 255 }),
+        ("peru", Color{ red: 205, green: 133, blue: 63, alpha: 255 }),
+        ("darkmagenta", Color{ red: 139, green: 0, blue: 139, alpha: 255 }),
+        ("saddlebrown", Color{ red: 139, green: 69, blue: 19, alpha: 255 }),
+        ("orangered", Color{ red: 255, green: 69, blue: 0, alpha: 255 }),
+        ("lightskyblue", Color{ red: 135, green: 206, blue: 250, alpha: 255 }),
+        ("lightcoral", Color{ red: 240, green: 128, blue: 128, alpha: 255 }),
+        ("snow", Color{ red: 255, green: 250, blue: 250, alpha: 255 }),
+        ("mediumpurple", Color{ red: 147, green: 112, blue: 219, alpha: 255 }),
+        ("skyblue", Color{ red: 135, green: 206, blue: 235, alpha: 255 }),
+        ("goldenrod", Color{ red: 218, green: 165, blue: 32, alpha: 255 }),
+        ("indigo", Color{ red: 75, green: 0, blue: 130, alpha: 255 }),
+        ("mediumturquoise", Color{ red: 72, green: 209, blue: 204, alpha: 255 }),
+        ("darksalmon", Color{ red: 233, green: 150, blue: 122, alpha: 255 }),
+        ("olivedrab", Color{ red: 107, green: 142, blue: 35, alpha: 255 }),
+        ("blanchedalmond", Color{ red: 255, green: 235, blue: 205, alpha: 255 }),
+        ("turquoise", Color{ red: 64, green: 224, blue: 208, alpha: 255 }),
+        ("darkslateblue", Color{ red: 72, green: 61, blue: 139, alpha: 255 }),
+        ("darkturquoise", Color{ red: 0, green: 206, blue: 209, alpha: 255 }),
+        ("thistle", Color{ red: 216, green: 191, blue: 216, alpha: 255 }),
+        ("slategrey", Color{ red: 112, green: 128, blue: 144, alpha: 255 }),
+        ("maroon", Color{ red: 128, green: 0, blue: 0, alpha: 255 }),
+        ("palegreen", Color{ red: 152, green: 251, blue: 152, alpha: 255 }),
+        ("darkcyan", Color{ red: 0, green: 139, blue: 139, alpha: 255 }),
+        ("tan", Color{ red: 210, green: 180, blue: 140, alpha: 255 }),
+        ("darkkhaki", Color{ red: 189, green: 183, blue: 107, alpha: 255 }),
+        ("bisque", Color{ red: 255, green: 228, blue: 196, alpha: 255 }),
+        ("teal", Color{ red: 0, green: 128, blue: 128, alpha: 255 }),
+        ("lightpink", Color{ red: 255, green: 182, blue: 193, alpha: 255 }),
+        ("slategray", Color{ red: 112, green: 128, blue: 144, alpha: 255 }),
+        ("gold", Color{ red: 255, green: 215, blue: 0, alpha: 255 }),
+        ("coral", Color{ red: 255, green: 127, blue: 80, alpha: 255 }),
+        ("cyan", Color{ red: 0, green: 255, blue: 255, alpha: 255 }),
+        ("orchid", Color{ red: 218, green: 112, blue: 214, alpha: 255 }),
+        ("violet", Color{ red: 238, green: 130, blue: 238, alpha: 255 }),
+        ("dimgray", Color{ red: 105, green: 105, blue: 105, alpha: 255 }),
+        ("ivory", Color{ red: 255, green: 255, blue: 240, alpha: 255 }),
+        ("lightsteelblue", Color{ red: 176, green: 196, blue: 222, alpha: 255 }),
+        ("mediumslateblue", Color{ red: 123, green: 104, blue: 238, alpha: 255 }),
     ]),
 };
 
 pub fn rgb_color_from_name(text: &str) -> Option<Color> {
     COLORS.get(text).cloned()
 }
+
+pub fn rgb_color_entry_from_name(text: &str) -> Option<(&'static str, Color)> {
+    COLORS.get_entry(text).map(|(k, v)| (*k, *v))
+}
+
+static COLOR_NAMES: &[((u8, u8, u8), &str)] = &[
+    ((0, 0, 0), "black"),
+    ((0, 0, 128), "navy"),
+    ((0, 0, 139), "darkblue"),
+    ((0, 0, 205), "mediumblue"),
+    ((0, 0, 255), "blue"),
+    ((0, 100, 0), "darkgreen"),
+    ((0, 128, 0), "green"),
+    ((0, 128, 128), "teal"),
+    ((0, 139, 139), "darkcyan"),
+    ((0, 191, 255), "deepskyblue"),
+    ((0, 206, 209), "darkturquoise"),
+    ((0, 250, 154), "mediumspringgreen"),
+    ((0, 255, 0), "lime"),
+    ((0, 255, 127), "springgreen"),
+    ((0, 255, 255), "aqua"),
+    ((25, 25, 112), "midnightblue"),
+    ((30, 144, 255), "dodgerblue"),
+    ((32, 178, 170), "lightseagreen"),
+    ((34, 139, 34), "forestgreen"),
+    ((46, 139, 87), "seagreen"),
+    ((47, 79, 79), "darkslategray"),
+    ((50, 205, 50), "limegreen"),
+    ((60, 179, 113), "mediumseagreen"),
+    ((64, 224, 208), "turquoise"),
+    ((65, 105, 225), "royalblue"),
+    ((70, 130, 180), "steelblue"),
+    ((72, 61, 139), "darkslateblue"),
+    ((72, 209, 204), "mediumturquoise"),
+    ((75, 0, 130), "indigo"),
+    ((85, 107, 47), "darkolivegreen"),
+    ((95, 158, 160), "cadetblue"),
+    ((100, 149, 237), "cornflowerblue"),
+    ((102, 205, 170), "mediumaquamarine"),
+    ((105, 105, 105), "dimgray"),
+    ((106, 90, 205), "slateblue"),
+    ((107, 142, 35), "olivedrab"),
+    ((112, 128, 144), "slategray"),
+    ((119, 136, 153), "lightslategray"),
+    ((123, 104, 238), "mediumslateblue"),
+    ((124, 252, 0), "lawngreen"),
+    ((127, 255, 0), "chartreuse"),
+    ((127, 255, 212), "aquamarine"),
+    ((128, 0, 0), "maroon"),
+    ((128, 0, 128), "purple"),
+    ((128, 128, 0), "olive"),
+    ((128, 128, 128), "gray"),
+    ((135, 206, 235), "skyblue"),
+    ((135, 206, 250), "lightskyblue"),
+    ((138, 43, 226), "blueviolet"),
+    ((139, 0, 0), "darkred"),
+    ((139, 0, 139), "darkmagenta"),
+    ((139, 69, 19), "saddlebrown"),
+    ((143, 188, 143), "darkseagreen"),
+    ((144, 238, 144), "lightgreen"),
+    ((147, 112, 219), "mediumpurple"),
+    ((148, 0, 211), "darkviolet"),
+    ((152, 251, 152), "palegreen"),
+    ((153, 50, 204), "darkorchid"),
+    ((154, 205, 50), "yellowgreen"),
+    ((160, 82, 45), "sienna"),
+    ((165, 42, 42), "brown"),
+    ((169, 169, 169), "darkgray"),
+    ((173, 216, 230), "lightblue"),
+    ((173, 255, 47), "greenyellow"),
+    ((175, 238, 238), "paleturquoise"),
+    ((176, 196, 222), "lightsteelblue"),
+    ((176, 224, 230), "powderblue"),
+    ((178, 34, 34), "firebrick"),
+    ((184, 134, 11), "darkgoldenrod"),
+    ((186, 85, 211), "mediumorchid"),
+    ((188, 143, 143), "rosybrown"),
+    ((189, 183, 107), "darkkhaki"),
+    ((192, 192, 192), "silver"),
+    ((199, 21, 133), "mediumvioletred"),
+    ((205, 92, 92), "indianred"),
+    ((205, 133, 63), "peru"),
+    ((210, 105, 30), "chocolate"),
+    ((210, 180, 140), "tan"),
+    ((211, 211, 211), "lightgray"),
+    ((216, 191, 216), "thistle"),
+    ((218, 112, 214), "orchid"),
+    ((218, 165, 32), "goldenrod"),
+    ((219, 112, 147), "palevioletred"),
+    ((220, 20, 60), "crimson"),
+    ((220, 220, 220), "gainsboro"),
+    ((221, 160, 221), "plum"),
+    ((222, 184, 135), "burlywood"),
+    ((224, 255, 255), "lightcyan"),
+    ((230, 230, 250), "lavender"),
+    ((233, 150, 122), "darksalmon"),
+    ((238, 130, 238), "violet"),
+    ((238, 232, 170), "palegoldenrod"),
+    ((240, 128, 128), "lightcoral"),
+    ((240, 230, 140), "khaki"),
+    ((240, 248, 255), "aliceblue"),
+    ((240, 255, 240), "honeydew"),
+    ((240, 255, 255), "azure"),
+    ((244, 164, 96), "sandybrown"),
+    ((245, 222, 179), "wheat"),
+    ((245, 245, 220), "beige"),
+    ((245, 245, 245), "whitesmoke"),
+    ((245, 255, 250), "mintcream"),
+    ((248, 248, 255), "ghostwhite"),
+    ((250, 128, 114), "salmon"),
+    ((250, 235, 215), "antiquewhite"),
+    ((250, 240, 230), "linen"),
+    ((250, 250, 210), "lightgoldenrodyellow"),
+    ((253, 245, 230), "oldlace"),
+    ((255, 0, 0), "red"),
+    ((255, 0, 255), "fuchsia"),
+    ((255, 20, 147), "deeppink"),
+    ((255, 69, 0), "orangered"),
+    ((255, 99, 71), "tomato"),
+    ((255, 105, 180), "hotpink"),
+    ((255, 127, 80), "coral"),
+    ((255, 140, 0), "darkorange"),
+    ((255, 160, 122), "lightsalmon"),
+    ((255, 165, 0), "orange"),
+    ((255, 182, 193), "lightpink"),
+    ((255, 192, 203), "pink"),
+    ((255, 215, 0), "gold"),
+    ((255, 218, 185), "peachpuff"),
+    ((255, 222, 173), "navajowhite"),
+    ((255, 228, 181), "moccasin"),
+    ((255, 228, 196), "bisque"),
+    ((255, 228, 225), "mistyrose"),
+    ((255, 235, 205), "blanchedalmond"),
+    ((255, 239, 213), "papayawhip"),
+    ((255, 240, 245), "lavenderblush"),
+    ((255, 245, 238), "seashell"),
+    ((255, 248, 220), "cornsilk"),
+    ((255, 250, 205), "lemonchiffon"),
+    ((255, 250, 240), "floralwhite"),
+    ((255, 250, 250), "snow"),
+    ((255, 255, 0), "yellow"),
+    ((255, 255, 224), "lightyellow"),
+    ((255, 255, 240), "ivory"),
+    ((255, 255, 255), "white"),
+];
+
+pub fn rgb_color_to_name(red: u8, green: u8, blue: u8) -> Option<&'static str> {
+    COLOR_NAMES.binary_search_by_key(&(red, green, blue), |v| v.0).ok().map(|i| COLOR_NAMES[i].1)
+}
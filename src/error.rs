@@ -6,18 +6,172 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::fmt;
+
 use xmlparser::{
     self,
     ErrorPos,
+    Stream,
+};
+
+use {
+    AttributeId,
+    ElementId,
 };
 
+/// A position range of an error.
+///
+/// Both positions are inclusive, so a single character error has `start == end`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ErrorSpan {
+    /// Position of the first invalid character.
+    pub start: ErrorPos,
+    /// Position of the last invalid character.
+    pub end: ErrorPos,
+}
+
+impl ErrorSpan {
+    /// Creates a new `ErrorSpan`.
+    pub fn new(start: ErrorPos, end: ErrorPos) -> Self {
+        ErrorSpan { start, end }
+    }
+
+    // Creates a span for the `start..end` stream region.
+    pub(crate) fn from_stream(s: &mut Stream, start: usize, end: usize) -> Self {
+        let end = if end > start { end - 1 } else { start };
+        ErrorSpan::new(s.gen_error_pos_from(start), s.gen_error_pos_from(end))
+    }
+
+    /// Shifts the span by the `base` position.
+    ///
+    /// See [`ErrorPosExt::offset_by`] for details.
+    ///
+    /// [`ErrorPosExt::offset_by`]: trait.ErrorPosExt.html#tymethod.offset_by
+    pub fn offset_by(&self, base: ErrorPos) -> Self {
+        ErrorSpan::new(self.start.offset_by(base), self.end.offset_by(base))
+    }
+}
+
+/// `ErrorPos` additional methods.
+pub trait ErrorPosExt {
+    /// Shifts the position by the `base` position.
+    ///
+    /// When a value was parsed from a string extracted from a larger document,
+    /// like a result of `strip_comments`, error positions are relative to this string.
+    /// `base` is a position of the first character of the string in the document.
+    ///
+    /// Only the first line of the string is shifted by the `base` column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::ErrorPosExt;
+    /// use svgparser::xmlparser::ErrorPos;
+    ///
+    /// let base = ErrorPos::new(3, 10);
+    /// assert_eq!(ErrorPos::new(1, 5).offset_by(base), ErrorPos::new(3, 14));
+    /// assert_eq!(ErrorPos::new(2, 5).offset_by(base), ErrorPos::new(4, 5));
+    /// ```
+    fn offset_by(&self, base: ErrorPos) -> ErrorPos;
+}
+
+impl ErrorPosExt for ErrorPos {
+    fn offset_by(&self, base: ErrorPos) -> ErrorPos {
+        if self.row == 1 {
+            ErrorPos::new(base.row, base.col + self.col - 1)
+        } else {
+            ErrorPos::new(base.row + self.row - 1, self.col)
+        }
+    }
+}
+
+/// Shows only the start position.
+impl fmt::Display for ErrorSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.start)
+    }
+}
+
+/// A cache of line start positions.
+///
+/// `Stream::gen_error_pos` scans the whole text on each call,
+/// which is slow when a large document has a lot of errors.
+/// `LineIndex` collects newline offsets on the first request
+/// and uses a binary search afterwards.
+///
+/// Produces the same positions as `Stream::gen_error_pos`.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::LineIndex;
+/// use svgparser::xmlparser::ErrorPos;
+///
+/// let mut index = LineIndex::new("<svg>\n  <rect/>\n</svg>");
+/// assert_eq!(index.error_pos(0), ErrorPos::new(1, 1));
+/// assert_eq!(index.error_pos(8), ErrorPos::new(2, 3));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineIndex<'a> {
+    text: &'a str,
+    newlines: Option<Vec<usize>>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Creates a new `LineIndex`.
+    ///
+    /// `text` must be the whole document, i.e. `StrSpan::full_str`.
+    pub fn new(text: &'a str) -> Self {
+        LineIndex { text, newlines: None }
+    }
+
+    /// Calculates a position of the byte `offset` in the text.
+    pub fn error_pos(&mut self, offset: usize) -> ErrorPos {
+        if self.newlines.is_none() {
+            let list = self.text.bytes()
+                                .enumerate()
+                                .filter(|&(_, c)| c == b'\n')
+                                .map(|(i, _)| i)
+                                .collect();
+            self.newlines = Some(list);
+        }
+
+        let newlines = match self.newlines {
+            Some(ref list) => list,
+            None => unreachable!(),
+        };
+
+        // The number of newlines before the `pos`.
+        let count_before = |pos: usize| match newlines.binary_search(&pos) {
+            Ok(idx) | Err(idx) => idx,
+        };
+
+        let row = count_before(offset) + 1;
+
+        // `Stream` resets a column only after the first character of a line,
+        // so the newline right before the `offset` is ignored.
+        let col = match count_before(offset.saturating_sub(1)) {
+            0 => offset + 1,
+            n => offset - newlines[n - 1],
+        };
+
+        ErrorPos::new(row, col)
+    }
+}
+
 /// Additional errors for `xmlparser::StreamError`.
 #[derive(Fail, Debug)]
 pub enum StreamError {
     /// An invalid color.
+    ///
+    /// Not returned by the parser anymore. See `InvalidColorSpan`.
     #[fail(display = "invalid color at {}", _0)]
     InvalidColor(ErrorPos),
 
+    /// An invalid color with the span of the invalid data.
+    #[fail(display = "invalid color at {}", _0)]
+    InvalidColorSpan(ErrorSpan),
+
     /// An invalid number.
     #[fail(display = "invalid number at {}", _0)]
     InvalidNumber(ErrorPos),
@@ -31,9 +185,15 @@ pub enum StreamError {
     InvalidEntityRef(ErrorPos),
 
     /// An invalid transform prefix.
+    ///
+    /// Not returned by the parser anymore. See `InvalidTransformPrefixSpan`.
     #[fail(display = "invalid transform prefix at {}", _0)]
     InvalidTransformPrefix(ErrorPos),
 
+    /// An invalid transform prefix with the span of the transform name.
+    #[fail(display = "invalid transform prefix at {}", _0)]
+    InvalidTransformPrefixSpan(ErrorSpan),
+
     /// An invalid align type.
     #[fail(display = "'{}' is an invalid align type", _0)]
     InvalidAlignType(String),
@@ -50,10 +210,45 @@ pub enum StreamError {
     #[fail(display = "expected a FuncIRI not '{}'", _0)]
     NotAFuncIRI(String),
 
+    /// A FuncIRI with an empty link or without a closing paren:
+    /// `url(#)` or `url(#id`.
+    #[fail(display = "invalid FuncIRI at {}", _0)]
+    InvalidFuncIRI(ErrorPos),
+
     /// A viewBox with a negative or zero size.
     #[fail(display = "viewBox should have a positive size")]
     InvalidViewbox,
 
+    /// A viewBox with less than four numbers.
+    #[fail(display = "viewBox should contain four numbers")]
+    IncompleteViewbox,
+
+    /// A `keySplines` group with not exactly four numbers.
+    #[fail(display = "a key spline should contain four numbers at {}", _0)]
+    InvalidKeySpline(ErrorPos),
+
+    /// An `feColorMatrix` `values` with an invalid amount of numbers for its `type`.
+    #[fail(display = "color matrix values should contain {} number(s), not {}", _0, _1)]
+    InvalidColorMatrixValues(usize, usize),
+
+    /// An invalid SMIL clock value.
+    #[fail(display = "invalid clock value at {}", _0)]
+    InvalidClockValue(ErrorPos),
+
+    /// An invalid path arc flag. Only `0` and `1` are allowed.
+    #[fail(display = "invalid arc flag at {}", _0)]
+    InvalidArcFlag(ErrorPos),
+
+    /// An invalid angle.
+    #[fail(display = "invalid angle at {}", _0)]
+    InvalidAngle(ErrorPos),
+
+    /// An attribute without a value parser.
+    ///
+    /// Returned only by `AttributeValue::from_span_strict`.
+    #[fail(display = "the '{}' attribute value can't be parsed", _0)]
+    UnknownAttributeValue(AttributeId),
+
     /// An XML stream error.
     #[fail(display = "{}", _0)]
     XmlError(xmlparser::StreamError),
@@ -66,3 +261,49 @@ impl From<xmlparser::StreamError> for StreamError {
 }
 
 pub type StreamResult<T> = ::std::result::Result<T, StreamError>;
+
+/// An attribute value parsing error.
+///
+/// Contains the element and the attribute that failed to parse.
+#[derive(Fail, Debug)]
+#[fail(display = "failed to parse the '{}' attribute of the '{}' element: {}", aid, eid, source)]
+pub struct AttributeError {
+    /// An element ID.
+    pub eid: ElementId,
+    /// An attribute ID.
+    pub aid: AttributeId,
+    /// An actual error.
+    #[cause]
+    pub source: StreamError,
+}
+
+/// Discards the attribute context.
+impl From<AttributeError> for StreamError {
+    fn from(e: AttributeError) -> Self {
+        e.source
+    }
+}
+
+/// A typed style tokenizer error.
+#[derive(Fail, Debug)]
+pub enum StyleError {
+    /// A style data error.
+    #[fail(display = "{}", _0)]
+    Stream(StreamError),
+
+    /// An attribute value error.
+    #[fail(display = "{}", _0)]
+    Attribute(AttributeError),
+}
+
+impl From<StreamError> for StyleError {
+    fn from(v: StreamError) -> Self {
+        StyleError::Stream(v)
+    }
+}
+
+impl From<AttributeError> for StyleError {
+    fn from(v: AttributeError) -> Self {
+        StyleError::Attribute(v)
+    }
+}
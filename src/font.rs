@@ -0,0 +1,166 @@
+// Copyright 2018 Evgeniy Reizner
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use xmlparser::{
+    self,
+    Stream,
+    StrSpan,
+    XmlByteExt,
+};
+
+use error::{
+    StreamError,
+    StreamResult,
+};
+use {
+    AttributeId,
+    AttributeValue,
+    ElementId,
+    Length,
+    ValueId,
+};
+
+/// A `font-size` value of the `font` shorthand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontSize {
+    /// An absolute or relative size keyword. Like `large` or `smaller`.
+    PredefValue(ValueId),
+    /// [`<length>`] type.
+    ///
+    /// [`<length>`]: https://www.w3.org/TR/SVG/types.html#DataTypeLength
+    Length(Length),
+}
+
+/// Representation of the [`font`] shorthand property.
+///
+/// [`font`]: https://www.w3.org/TR/CSS2/fonts.html#font-shorthand
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontShorthand<'a> {
+    /// `font-style` value. `None` if not set.
+    pub style: Option<ValueId>,
+    /// `font-variant` value. `None` if not set.
+    pub variant: Option<ValueId>,
+    /// `font-weight` value. `None` if not set.
+    pub weight: Option<ValueId>,
+    /// `font-size` value.
+    pub size: FontSize,
+    /// `line-height` value. `None` if not set or set to `normal`.
+    pub line_height: Option<Length>,
+    /// `font-family` value as is.
+    pub family: &'a str,
+}
+
+impl<'a> FontShorthand<'a> {
+    /// Parses `FontShorthand` from `StrSpan`.
+    ///
+    /// Parsing is done according to:
+    ///
+    /// ```text
+    /// font ::= [ <font-style> || <font-variant> || <font-weight> ]?
+    ///          <font-size> [ / <line-height> ]? <font-family>
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - Returns error if `font-size` or `font-family` are not set.
+    /// - Returns error on an invalid `font-size` or `line-height`.
+    ///
+    /// # Notes
+    ///
+    /// - System fonts, like `caption`, and `inherit` are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::{FontShorthand, FontSize, Length, LengthUnit, ValueId};
+    /// use svgparser::xmlparser::StrSpan;
+    ///
+    /// let font = FontShorthand::from_span(StrSpan::from_str("italic bold 12px/1.5 Arial")).unwrap();
+    /// assert_eq!(font.style, Some(ValueId::Italic));
+    /// assert_eq!(font.weight, Some(ValueId::Bold));
+    /// assert_eq!(font.size, FontSize::Length(Length::new(12.0, LengthUnit::Px)));
+    /// assert_eq!(font.line_height, Some(Length::new(1.5, LengthUnit::None)));
+    /// assert_eq!(font.family, "Arial");
+    /// ```
+    pub fn from_span(span: StrSpan<'a>) -> StreamResult<FontShorthand<'a>> {
+        let mut s = Stream::from_span(span);
+
+        let mut style = None;
+        let mut variant = None;
+        let mut weight = None;
+
+        // Style, variant and weight can be set in any order.
+        let mut size_span;
+        loop {
+            s.skip_spaces();
+            size_span = consume_token(&mut s);
+            if size_span.is_empty() {
+                return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
+            }
+
+            let id = match ValueId::from_name(size_span.to_str()) {
+                Some(id) => id,
+                None => break,
+            };
+
+            match id {
+                ValueId::Normal => {}
+                ValueId::Italic | ValueId::Oblique if style.is_none() => {
+                    style = Some(id);
+                }
+                ValueId::SmallCaps if variant.is_none() => {
+                    variant = Some(id);
+                }
+                  ValueId::Bold | ValueId::Bolder | ValueId::Lighter
+                | ValueId::N100 | ValueId::N200 | ValueId::N300
+                | ValueId::N400 | ValueId::N500 | ValueId::N600
+                | ValueId::N700 | ValueId::N800 | ValueId::N900 if weight.is_none() => {
+                    weight = Some(id);
+                }
+                _ => break,
+            }
+        }
+
+        let size = AttributeValue::from_span(ElementId::Text, "", AttributeId::FontSize,
+                                             size_span)?;
+        let size = match size {
+            AttributeValue::Length(l) => FontSize::Length(l),
+            AttributeValue::PredefValue(v) if v != ValueId::Inherit => FontSize::PredefValue(v),
+            _ => return Err(StreamError::InvalidPredefValue(size_span.to_str().into())),
+        };
+
+        s.skip_spaces();
+        let mut line_height = None;
+        if s.is_curr_byte_eq(b'/') {
+            s.advance(1);
+            s.skip_spaces();
+            let span = consume_token(&mut s);
+            if span.to_str() != "normal" {
+                line_height = Some(Length::from_span(span)?);
+            }
+        }
+
+        let family = s.slice_tail().trim();
+        if family.is_empty() {
+            return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
+        }
+
+        Ok(FontShorthand {
+            style,
+            variant,
+            weight,
+            size,
+            line_height,
+            family: family.to_str(),
+        })
+    }
+}
+
+fn consume_token<'a>(s: &mut Stream<'a>) -> StrSpan<'a> {
+    s.consume_bytes(|_, c| !c.is_xml_space() && c != b'/')
+}
@@ -6,6 +6,23 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::cmp;
+use std::fmt;
+use std::str::FromStr;
+
+use xmlparser::{
+    Stream,
+    StrSpan,
+};
+
+use error::{
+    StreamError,
+    StreamResult,
+};
+use {
+    StreamExt,
+};
+
 /// List of all SVG length units.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[allow(missing_docs)]
@@ -37,4 +54,89 @@ impl Length {
     pub fn new(num: f64, unit: LengthUnit) -> Length {
         Length { num, unit, }
     }
+
+    /// Parses `Length` from `StrSpan`.
+    ///
+    /// Unlike `StreamExt::parse_length`, the whole span must be a single length.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLength` when a length is followed by any data.
+    pub fn from_span(span: StrSpan) -> StreamResult<Length> {
+        let mut s = Stream::from_span(span);
+        let l = s.parse_length()?;
+
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(StreamError::InvalidLength(s.gen_error_pos()));
+        }
+
+        Ok(l)
+    }
+
+    /// Compares two lengths with the specified precision.
+    ///
+    /// Units are compared exactly, so `1in` is not equal to `96px`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::{Length, LengthUnit};
+    ///
+    /// let l1 = Length::new(10.0, LengthUnit::Px);
+    /// let l2 = Length::new(10.0000001, LengthUnit::Px);
+    /// assert!(l1.approx_eq(&l2, 0.001));
+    /// assert!(!l1.approx_eq(&Length::new(10.0, LengthUnit::Mm), 0.001));
+    /// ```
+    pub fn approx_eq(&self, other: &Length, eps: f64) -> bool {
+        self.unit == other.unit && (self.num - other.num).abs() <= eps
+    }
+}
+
+impl FromStr for Length {
+    type Err = StreamError;
+
+    fn from_str(text: &str) -> StreamResult<Self> {
+        Length::from_span(StrSpan::from_str(text))
+    }
+}
+
+/// Lengths with different units are incomparable.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::{Length, LengthUnit};
+///
+/// assert!(Length::new(1.0, LengthUnit::Px) < Length::new(2.0, LengthUnit::Px));
+/// assert_eq!(Length::new(1.0, LengthUnit::Px).partial_cmp(&Length::new(2.0, LengthUnit::Em)),
+///            None);
+/// ```
+impl PartialOrd for Length {
+    fn partial_cmp(&self, other: &Length) -> Option<cmp::Ordering> {
+        if self.unit == other.unit {
+            self.num.partial_cmp(&other.num)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = match self.unit {
+            LengthUnit::None => "",
+            LengthUnit::Em => "em",
+            LengthUnit::Ex => "ex",
+            LengthUnit::Px => "px",
+            LengthUnit::In => "in",
+            LengthUnit::Cm => "cm",
+            LengthUnit::Mm => "mm",
+            LengthUnit::Pt => "pt",
+            LengthUnit::Pc => "pc",
+            LengthUnit::Percent => "%",
+        };
+
+        write!(f, "{}{}", self.num, unit)
+    }
 }
@@ -44,18 +44,30 @@ but also supports [SVG types](https://www.w3.org/TR/SVG/types.html#BasicDataType
 
 ### Limitations
  - All keywords must be lowercase.
-   Case-insensitive parsing is supported only for colors (requires allocation for named colors).
+   Case-insensitive parsing is supported only for colors.
    Still, it's extremely rare.
  - The `<color>` followed by the `<icccolor>` is not supported. As the `<icccolor>` itself.
  - Only ENTITY objects are parsed from the DOCTYPE. Other ignored.
  - CSS styles does not processed. You should use an external CSS parser.
  - Comments inside attributes value supported only for the `style` attribute.
+   `strip_comments` can be used to remove them from other values.
  - [System colors](https://www.w3.org/TR/css3-color/#css2-system), like `fill="AppWorkspace"`, are not supported.
- - There is no separate `opacity-value` type. It will be parsed as `<number>`,
+ - There is no separate `opacity-value` type. It will be parsed as `<number>` or a percentage,
    but will be bound to 0..1 range.
  - Implicit path commands are not supported. All commands are parsed as explicit.
  - Implicit MoveTo commands will be automatically converted into explicit LineTo.
 
+### End of data
+
+ - All tokenizers and lists return `None` when the data has ended cleanly.
+   Trailing spaces and an empty input are not an error.
+ - `UnexpectedEndOfStream` is returned only when data was truncated
+   in the middle of a value. Like `rgb(10, 20`.
+ - Value tokenizers return `None` after the first error, since there is no way to recover.
+   `transform::parse_all_reporting` can be used to skip invalid transforms instead.
+   `svg::Tokenizer` is an exception: it continues after the closing tags mismatch
+   and the attributes limit errors.
+
 ### Safety
 
  - The library should not panic. Any panic considered as a critical bug
@@ -74,6 +86,7 @@ pub extern crate xmlparser;
 extern crate phf;
 #[macro_use] extern crate log;
 #[macro_use] extern crate failure;
+#[cfg(feature = "serde")] extern crate serde;
 
 
 macro_rules! try_opt {
@@ -85,6 +98,17 @@ macro_rules! try_opt {
     }
 }
 
+#[inline]
+fn f64_bound(min: f64, val: f64, max: f64) -> f64 {
+    if val > max {
+        return max;
+    } else if val < min {
+        return min;
+    }
+
+    val
+}
+
 
 pub mod path;
 pub mod style;
@@ -94,13 +118,18 @@ pub mod transform;
 mod aspect_ratio;
 mod attribute_id;
 mod attribute_value;
+mod categories;
+mod clock_value;
 mod color;
 mod colors;
 mod element_id;
 mod error;
+mod font;
 mod length;
 mod points;
+#[cfg(feature = "serde")] mod serde_impls;
 mod streamext;
+mod text;
 mod value_id;
 mod values_list;
 
@@ -108,11 +137,21 @@ mod values_list;
 pub use aspect_ratio::*;
 pub use attribute_id::*;
 pub use attribute_value::*;
+pub use clock_value::*;
 pub use color::*;
 pub use element_id::*;
-pub use error::StreamError;
+pub use error::{
+    AttributeError,
+    ErrorPosExt,
+    ErrorSpan,
+    LineIndex,
+    StreamError,
+    StyleError,
+};
+pub use font::*;
 pub use length::*;
 pub use points::*;
 pub use streamext::*;
+pub use text::*;
 pub use value_id::*;
 pub use values_list::*;
@@ -10,14 +10,20 @@
 //!
 //! [`<path>`]: https://www.w3.org/TR/SVG/paths.html#PathData
 
+use std::f64;
 use std::fmt;
 
 use xmlparser::{
+    self,
     FromSpan,
     Stream,
     StrSpan,
 };
 
+use error::{
+    StreamError,
+    StreamResult,
+};
 use {
     StreamExt,
 };
@@ -87,11 +93,90 @@ pub enum Token {
     },
 }
 
+impl Token {
+    /// Compares two tokens with the specified precision.
+    ///
+    /// Tokens are equal if they have the same type and the same `abs` flag,
+    /// and all their numeric values differ no more than `eps`.
+    /// Arc flags are compared exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::path::Token;
+    ///
+    /// let t1 = Token::LineTo { abs: true, x: 10.0, y: 20.0 };
+    /// let t2 = Token::LineTo { abs: true, x: 10.0000001, y: 20.0 };
+    /// assert!(t1.approx_eq(&t2, 0.001));
+    /// assert!(!t1.approx_eq(&t2, 0.0));
+    /// ```
+    pub fn approx_eq(&self, other: &Token, eps: f64) -> bool {
+        let eq = |a: f64, b: f64| (a - b).abs() <= eps;
+
+        match (*self, *other) {
+            (Token::MoveTo { abs, x, y },
+             Token::MoveTo { abs: abs2, x: x_2, y: y_2 }) => {
+                abs == abs2 && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::LineTo { abs, x, y },
+             Token::LineTo { abs: abs2, x: x_2, y: y_2 }) => {
+                abs == abs2 && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::HorizontalLineTo { abs, x },
+             Token::HorizontalLineTo { abs: abs2, x: x_2 }) => {
+                abs == abs2 && eq(x, x_2)
+            }
+            (Token::VerticalLineTo { abs, y },
+             Token::VerticalLineTo { abs: abs2, y: y_2 }) => {
+                abs == abs2 && eq(y, y_2)
+            }
+            (Token::CurveTo { abs, x1, y1, x2, y2, x, y },
+             Token::CurveTo { abs: abs2, x1: x1_2, y1: y1_2, x2: x2_2, y2: y2_2, x: x_2, y: y_2 }) => {
+                   abs == abs2
+                && eq(x1, x1_2) && eq(y1, y1_2)
+                && eq(x2, x2_2) && eq(y2, y2_2)
+                && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::SmoothCurveTo { abs, x2, y2, x, y },
+             Token::SmoothCurveTo { abs: abs2, x2: x2_2, y2: y2_2, x: x_2, y: y_2 }) => {
+                   abs == abs2
+                && eq(x2, x2_2) && eq(y2, y2_2)
+                && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::Quadratic { abs, x1, y1, x, y },
+             Token::Quadratic { abs: abs2, x1: x1_2, y1: y1_2, x: x_2, y: y_2 }) => {
+                   abs == abs2
+                && eq(x1, x1_2) && eq(y1, y1_2)
+                && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::SmoothQuadratic { abs, x, y },
+             Token::SmoothQuadratic { abs: abs2, x: x_2, y: y_2 }) => {
+                abs == abs2 && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::EllipticalArc { abs, rx, ry, x_axis_rotation, large_arc, sweep, x, y },
+             Token::EllipticalArc { abs: abs2, rx: rx_2, ry: ry_2, x_axis_rotation: rot_2,
+                                    large_arc: large_arc_2, sweep: sweep_2, x: x_2, y: y_2 }) => {
+                   abs == abs2
+                && eq(rx, rx_2) && eq(ry, ry_2)
+                && eq(x_axis_rotation, rot_2)
+                && large_arc == large_arc_2 && sweep == sweep_2
+                && eq(x, x_2) && eq(y, y_2)
+            }
+            (Token::ClosePath { abs }, Token::ClosePath { abs: abs2 }) => {
+                abs == abs2
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Tokenizer for the \<path\> data.
 #[derive(Clone, Copy, PartialEq)]
 pub struct Tokenizer<'a> {
     stream: Stream<'a>,
     prev_cmd: Option<u8>,
+    is_implicit: bool,
+    is_empty_input: bool,
 }
 
 impl<'a> FromSpan<'a> for Tokenizer<'a> {
@@ -99,41 +184,117 @@ impl<'a> FromSpan<'a> for Tokenizer<'a> {
         Tokenizer {
             stream: Stream::from_span(span),
             prev_cmd: None,
+            is_implicit: false,
+            is_empty_input: span.trim().is_empty(),
         }
     }
 }
 
-impl<'a> fmt::Debug for Tokenizer<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "PathTokenizer({:?})", self.stream.span())
+impl<'a> Tokenizer<'a> {
+    /// Checks that the last token was parsed from an implicit command.
+    ///
+    /// An implicit command is a coordinates sequence without a command letter,
+    /// which repeats the previous command. Coordinates after a MoveTo are returned
+    /// as `LineTo`, so this flag allows to restore the original data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::path::{Tokenizer, Token};
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut p = Tokenizer::from_str("M 10 20 30 40");
+    /// assert_eq!(p.next().unwrap(), Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+    /// assert!(!p.is_implicit());
+    /// assert_eq!(p.next().unwrap(), Token::LineTo { abs: true, x: 30.0, y: 40.0 });
+    /// assert!(p.is_implicit());
+    /// ```
+    pub fn is_implicit(&self) -> bool {
+        self.is_implicit
     }
-}
 
-impl<'a> Iterator for Tokenizer<'a> {
-    type Item = Token;
+    /// Checks that the input data is empty or contains only spaces.
+    ///
+    /// The tokenizer doesn't produce any tokens for an invalid path data too,
+    /// so this flag allows to distinguish an empty path from an invalid one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::path::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// assert!(Tokenizer::from_str(" ").is_empty_input());
+    ///
+    /// let mut p = Tokenizer::from_str("!!!");
+    /// assert!(p.next().is_none());
+    /// assert!(!p.is_empty_input());
+    /// ```
+    pub fn is_empty_input(&self) -> bool {
+        self.is_empty_input
+    }
 
-    /// Extracts next path data segment from the stream.
+    /// Returns the data that was not parsed yet.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// - By the SVG spec any invalid data inside path data should stop parsing of this data,
-    ///   but not the whole document.
+    /// ```
+    /// use svgparser::path::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
     ///
-    ///   This function will return `Error::EndOfStream` on any kind of error
-    ///   and print a warning to stderr.
+    /// let mut p = Tokenizer::from_str("M 10 20 L 30 40");
+    /// p.next();
+    /// assert_eq!(p.remaining().to_str(), "L 30 40");
+    /// ```
+    pub fn remaining(&self) -> StrSpan<'a> {
+        let mut s = self.stream;
+        s.slice_tail()
+    }
+
+    /// Returns a new tokenizer over the same data, positioned at the start.
     ///
-    ///   In other words, you will retrieve as much data as possible.
+    /// Useful for a multi-pass processing, like counting segments before parsing them.
     ///
-    ///   Example: `M 10 20 L 30 40 #!@$1 L 50 60` -> `M 10 20 L 30 40`
+    /// # Examples
     ///
-    /// # Notes
+    /// ```
+    /// use svgparser::path::{Tokenizer, Token};
+    /// use svgparser::xmlparser::FromSpan;
     ///
-    /// - We do not support implicit commands, so all commands will be converted to explicit one.
-    ///   It mostly affects implicit MoveTo, which will be converted, according to the spec,
-    ///   into explicit LineTo.
+    /// let mut p = Tokenizer::from_str("M 10 20 L 30 40");
+    /// assert_eq!(p.by_ref().count(), 2);
+    /// assert_eq!(p.restart().next().unwrap(), Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+    /// ```
+    pub fn restart(&self) -> Self {
+        Tokenizer::from_span(self.stream.span())
+    }
+
+    /// Extracts next path data segment from the stream and reports errors.
     ///
-    ///   Example: `M 10 20 30 40 50 60` -> `M 10 20 L 30 40 L 50 60`
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Unlike `next`, which silently stops on invalid data, this method
+    /// returns an error with a position. After an error the tokenizer is at the end.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `InvalidArcFlag` when an arc flag is not `0` or `1`.
+    /// - Returns `InvalidNumber` on an invalid coordinate.
+    /// - Returns `InvalidChar` on an unexpected command or when the first
+    ///   segment is not MoveTo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::path::{Tokenizer, Token};
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut p = Tokenizer::from_str("M 10 20 A 5 5 0 2 0 10 10");
+    /// assert_eq!(p.next_checked().unwrap().unwrap(),
+    ///            Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+    /// assert_eq!(p.next_checked().unwrap().unwrap_err().to_string(),
+    ///            "invalid arc flag at 1:17");
+    /// assert!(p.next_checked().is_none());
+    /// ```
+    pub fn next_checked(&mut self) -> Option<StreamResult<Token>> {
         let s = &mut self.stream;
 
         s.skip_spaces();
@@ -143,48 +304,41 @@ impl<'a> Iterator for Tokenizer<'a> {
         }
 
         macro_rules! data_error {
-            () => ({
-                warn!(
-                    "Invalid path data at {}. The remaining data is ignored.",
-                    s.gen_error_pos()
-                );
+            ($err:expr) => ({
+                let e = $err;
                 s.jump_to_end();
-                return None;
+                return Some(Err(e));
             })
         }
 
-        macro_rules! try_num {
+        macro_rules! invalid_char {
+            ($c:expr, $expected:expr) => (
+                data_error!(xmlparser::StreamError::InvalidChar(
+                    $c as char, $expected.into(), s.gen_error_pos()).into())
+            )
+        }
+
+        macro_rules! try_data {
             ($expr:expr) => (
                 match $expr {
                     Ok(v) => v,
-                    Err(_) => data_error!(),
+                    Err(e) => data_error!(e),
                 }
             )
         }
 
         macro_rules! parse_num {
-            () => ( try_num!(s.parse_list_number()); )
+            () => ( try_data!(s.parse_list_number()) )
         }
 
         let has_prev_cmd = self.prev_cmd.is_some();
         let first_char = s.curr_byte().unwrap(); // TODO: remove unwrap
 
-        if !has_prev_cmd && !is_cmd(first_char) {
-            warn!("'{}' is not a command. \
-                   The remaining data is ignored.", first_char as char);
-            s.jump_to_end();
-            return None;
-        }
-
+        // First segment must be MoveTo.
         if !has_prev_cmd {
             match first_char {
                 b'M' | b'm' => {}
-                _ => {
-                    warn!("First segment must be MoveTo. \
-                           The remaining data is ignored.");
-                    s.jump_to_end();
-                    return None;
-                }
+                _ => invalid_char!(first_char, "Mm"),
             }
         }
 
@@ -199,11 +353,9 @@ impl<'a> Iterator for Tokenizer<'a> {
             // unwrap is safe, because we checked 'has_prev_cmd'
             let prev_cmd = self.prev_cmd.unwrap();
 
+            // ClosePath cannot be followed by a number.
             if prev_cmd == b'Z' || prev_cmd == b'z' {
-                warn!("ClosePath cannot be followed by a number. \
-                       The remaining data is ignored.");
-                s.jump_to_end();
-                return None;
+                invalid_char!(first_char, "a command");
             }
 
             if prev_cmd == b'M' || prev_cmd == b'm' {
@@ -217,7 +369,7 @@ impl<'a> Iterator for Tokenizer<'a> {
                 cmd = prev_cmd;
             }
         } else {
-            data_error!();
+            invalid_char!(first_char, "a command or a number");
         }
 
         let cmdl = to_relative(cmd);
@@ -291,8 +443,8 @@ impl<'a> Iterator for Tokenizer<'a> {
                     rx: parse_num!(),
                     ry: parse_num!(),
                     x_axis_rotation: parse_num!(),
-                    large_arc: try_opt!(parse_flag(s)),
-                    sweep: try_opt!(parse_flag(s)),
+                    large_arc: try_data!(parse_flag(s)),
+                    sweep: try_data!(parse_flag(s)),
                     x: parse_num!(),
                     y: parse_num!(),
                 }
@@ -305,6 +457,7 @@ impl<'a> Iterator for Tokenizer<'a> {
             _ => unreachable!(),
         };
 
+        self.is_implicit = !is_cmd(first_char);
         self.prev_cmd = Some(
             if is_implicit_move_to {
                 if is_absolute(cmd) { b'M' } else { b'm' }
@@ -313,10 +466,423 @@ impl<'a> Iterator for Tokenizer<'a> {
             }
         );
 
-        Some(token)
+        Some(Ok(token))
     }
 }
 
+impl<'a> fmt::Debug for Tokenizer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PathTokenizer({:?})", self.stream.span())
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token;
+
+    /// Extracts next path data segment from the stream.
+    ///
+    /// # Errors
+    ///
+    /// - By the SVG spec any invalid data inside path data should stop parsing of this data,
+    ///   but not the whole document.
+    ///
+    ///   This function will return `None` on any kind of error
+    ///   and print a warning to stderr.
+    ///
+    ///   In other words, you will retrieve as much data as possible.
+    ///
+    ///   Example: `M 10 20 L 30 40 #!@$1 L 50 60` -> `M 10 20 L 30 40`
+    ///
+    /// # Notes
+    ///
+    /// - We do not support implicit commands, so all commands will be converted to explicit one.
+    ///   It mostly affects implicit MoveTo, which will be converted, according to the spec,
+    ///   into explicit LineTo.
+    ///
+    ///   Example: `M 10 20 30 40 50 60` -> `M 10 20 L 30 40 L 50 60`
+    ///
+    ///   Use `is_implicit` to check that a token was parsed from an implicit command.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_checked() {
+            Some(Ok(token)) => Some(token),
+            Some(Err(e)) => {
+                warn!("Invalid path data: {}. The remaining data is ignored.", e);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+/// Flattened path's segment token.
+///
+/// All coordinates are absolute.
+#[allow(missing_docs)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FlatToken {
+    /// Starts a new subpath.
+    MoveTo { x: f64, y: f64 },
+    LineTo { x: f64, y: f64 },
+}
+
+/// Converts path segments into a polyline.
+///
+/// Curves and arcs will be subdivided into line segments, so the distance between
+/// the polyline and the original curve is less than `tolerance`.
+///
+/// Each subpath starts with `FlatToken::MoveTo`. `ClosePath` will be converted into
+/// `LineTo` to the subpath start point.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::path::{flatten, Tokenizer, FlatToken};
+/// use svgparser::xmlparser::FromSpan;
+///
+/// let mut p = flatten(Tokenizer::from_str("M 10 20 h 10 Z"), 0.1);
+/// assert_eq!(p.next().unwrap(), FlatToken::MoveTo { x: 10.0, y: 20.0 });
+/// assert_eq!(p.next().unwrap(), FlatToken::LineTo { x: 20.0, y: 20.0 });
+/// assert_eq!(p.next().unwrap(), FlatToken::LineTo { x: 10.0, y: 20.0 });
+/// assert_eq!(p.next(), None);
+/// ```
+pub fn flatten(tokenizer: Tokenizer, tolerance: f64) -> Flatten {
+    Flatten {
+        tokenizer,
+        tolerance,
+        curve: None,
+        step: 0,
+        steps: 0,
+        prev_x: 0.0,
+        prev_y: 0.0,
+        start_x: 0.0,
+        start_y: 0.0,
+        prev_ctrl: None,
+    }
+}
+
+/// An iterator over the flattened path.
+///
+/// See [`flatten`] for details.
+///
+/// [`flatten`]: fn.flatten.html
+#[derive(Clone, Copy, PartialEq)]
+pub struct Flatten<'a> {
+    tokenizer: Tokenizer<'a>,
+    tolerance: f64,
+    curve: Option<Curve>,
+    step: u32,
+    steps: u32,
+    prev_x: f64,
+    prev_y: f64,
+    start_x: f64,
+    start_y: f64,
+    prev_ctrl: Option<Ctrl>,
+}
+
+// The last control point of the previous segment.
+// Used to resolve the SmoothCurveTo and SmoothQuadratic segments.
+#[derive(Clone, Copy, PartialEq)]
+enum Ctrl {
+    Cubic(f64, f64),
+    Quadratic(f64, f64),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Curve {
+    Cubic {
+        x0: f64, y0: f64,
+        x1: f64, y1: f64,
+        x2: f64, y2: f64,
+        x3: f64, y3: f64,
+    },
+    Quadratic {
+        x0: f64, y0: f64,
+        x1: f64, y1: f64,
+        x2: f64, y2: f64,
+    },
+    Arc {
+        cx: f64, cy: f64,
+        rx: f64, ry: f64,
+        sin_phi: f64, cos_phi: f64,
+        theta: f64, delta: f64,
+    },
+}
+
+impl Curve {
+    fn at(&self, t: f64) -> (f64, f64) {
+        match *self {
+            Curve::Cubic { x0, y0, x1, y1, x2, y2, x3, y3 } => {
+                let mt = 1.0 - t;
+                let a = mt * mt * mt;
+                let b = 3.0 * mt * mt * t;
+                let c = 3.0 * mt * t * t;
+                let d = t * t * t;
+                (a * x0 + b * x1 + c * x2 + d * x3, a * y0 + b * y1 + c * y2 + d * y3)
+            }
+            Curve::Quadratic { x0, y0, x1, y1, x2, y2 } => {
+                let mt = 1.0 - t;
+                let a = mt * mt;
+                let b = 2.0 * mt * t;
+                let c = t * t;
+                (a * x0 + b * x1 + c * x2, a * y0 + b * y1 + c * y2)
+            }
+            Curve::Arc { cx, cy, rx, ry, sin_phi, cos_phi, theta, delta } => {
+                let (sin, cos) = (theta + delta * t).sin_cos();
+                (cx + rx * cos_phi * cos - ry * sin_phi * sin,
+                 cy + rx * sin_phi * cos + ry * cos_phi * sin)
+            }
+        }
+    }
+
+    // The number of line segments required to approximate the curve
+    // with the selected tolerance.
+    fn steps(&self, tolerance: f64) -> u32 {
+        // Prevents an infinite subdivision.
+        const MAX_STEPS: f64 = 1024.0;
+
+        let n = match *self {
+            Curve::Cubic { x0, y0, x1, y1, x2, y2, x3, y3 } => {
+                // The distance between a cubic curve and a chord is bounded by
+                // `max|B''| / 8 / n^2`, where `max|B''| <= 6 * max(|d1|, |d2|)`.
+                let d1 = (x0 - 2.0 * x1 + x2).hypot(y0 - 2.0 * y1 + y2);
+                let d2 = (x1 - 2.0 * x2 + x3).hypot(y1 - 2.0 * y2 + y3);
+                (0.75 * d1.max(d2) / tolerance).sqrt()
+            }
+            Curve::Quadratic { x0, y0, x1, y1, x2, y2 } => {
+                // Same as above, but `|B''| = 2 * |d|`.
+                let d = (x0 - 2.0 * x1 + x2).hypot(y0 - 2.0 * y1 + y2);
+                (0.25 * d / tolerance).sqrt()
+            }
+            Curve::Arc { rx, ry, delta, .. } => {
+                // The distance between an arc and a chord is `r * (1 - cos(a / 2))`.
+                let r = rx.max(ry);
+                if tolerance < r {
+                    let a = 2.0 * (1.0 - tolerance / r).acos();
+                    delta.abs() / a
+                } else {
+                    1.0
+                }
+            }
+        };
+
+        if n.is_nan() || n > MAX_STEPS {
+            MAX_STEPS as u32
+        } else if n < 1.0 {
+            1
+        } else {
+            n.ceil() as u32
+        }
+    }
+}
+
+impl<'a> fmt::Debug for Flatten<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Flatten({:?}, {})", self.tokenizer, self.tolerance)
+    }
+}
+
+impl<'a> Iterator for Flatten<'a> {
+    type Item = FlatToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(curve) = self.curve {
+                if self.step < self.steps {
+                    self.step += 1;
+
+                    // The last point must be exactly the segment end point.
+                    let (x, y) = if self.step == self.steps {
+                        (self.prev_x, self.prev_y)
+                    } else {
+                        curve.at(self.step as f64 / self.steps as f64)
+                    };
+
+                    return Some(FlatToken::LineTo { x, y });
+                }
+
+                self.curve = None;
+            }
+
+            let token = try_opt!(self.tokenizer.next());
+            if let Some(t) = self.process_token(token) {
+                return Some(t);
+            }
+        }
+    }
+}
+
+impl<'a> Flatten<'a> {
+    fn process_token(&mut self, token: Token) -> Option<FlatToken> {
+        let (px, py) = (self.prev_x, self.prev_y);
+
+        macro_rules! abs {
+            ($abs:expr, $x:expr, $y:expr) => (
+                if $abs { ($x, $y) } else { ($x + px, $y + py) }
+            )
+        }
+
+        let mut ctrl = None;
+        let mut curve = None;
+        let (x, y) = match token {
+            Token::MoveTo { abs, x, y } => {
+                let (x, y) = abs!(abs, x, y);
+                self.start_x = x;
+                self.start_y = y;
+                self.prev_x = x;
+                self.prev_y = y;
+                self.prev_ctrl = None;
+                return Some(FlatToken::MoveTo { x, y });
+            }
+            Token::LineTo { abs, x, y } => {
+                abs!(abs, x, y)
+            }
+            Token::HorizontalLineTo { abs, x } => {
+                (if abs { x } else { x + px }, py)
+            }
+            Token::VerticalLineTo { abs, y } => {
+                (px, if abs { y } else { y + py })
+            }
+            Token::CurveTo { abs, x1, y1, x2, y2, x, y } => {
+                let (x1, y1) = abs!(abs, x1, y1);
+                let (x2, y2) = abs!(abs, x2, y2);
+                let (x, y) = abs!(abs, x, y);
+                ctrl = Some(Ctrl::Cubic(x2, y2));
+                curve = Some(Curve::Cubic { x0: px, y0: py, x1, y1, x2, y2, x3: x, y3: y });
+                (x, y)
+            }
+            Token::SmoothCurveTo { abs, x2, y2, x, y } => {
+                let (x1, y1) = match self.prev_ctrl {
+                    Some(Ctrl::Cubic(cx, cy)) => (2.0 * px - cx, 2.0 * py - cy),
+                    _ => (px, py),
+                };
+                let (x2, y2) = abs!(abs, x2, y2);
+                let (x, y) = abs!(abs, x, y);
+                ctrl = Some(Ctrl::Cubic(x2, y2));
+                curve = Some(Curve::Cubic { x0: px, y0: py, x1, y1, x2, y2, x3: x, y3: y });
+                (x, y)
+            }
+            Token::Quadratic { abs, x1, y1, x, y } => {
+                let (x1, y1) = abs!(abs, x1, y1);
+                let (x, y) = abs!(abs, x, y);
+                ctrl = Some(Ctrl::Quadratic(x1, y1));
+                curve = Some(Curve::Quadratic { x0: px, y0: py, x1, y1, x2: x, y2: y });
+                (x, y)
+            }
+            Token::SmoothQuadratic { abs, x, y } => {
+                let (x1, y1) = match self.prev_ctrl {
+                    Some(Ctrl::Quadratic(cx, cy)) => (2.0 * px - cx, 2.0 * py - cy),
+                    _ => (px, py),
+                };
+                let (x, y) = abs!(abs, x, y);
+                ctrl = Some(Ctrl::Quadratic(x1, y1));
+                curve = Some(Curve::Quadratic { x0: px, y0: py, x1, y1, x2: x, y2: y });
+                (x, y)
+            }
+            Token::EllipticalArc { abs, rx, ry, x_axis_rotation, large_arc, sweep, x, y } => {
+                let (x, y) = abs!(abs, x, y);
+
+                // 'If the endpoints are identical, then this is equivalent
+                // to omitting the elliptical arc segment entirely.'
+                if x == px && y == py {
+                    self.prev_ctrl = None;
+                    return None;
+                }
+
+                curve = arc_to_curve((px, py), (x, y), (rx, ry), x_axis_rotation, large_arc, sweep);
+                (x, y)
+            }
+            Token::ClosePath { .. } => {
+                (self.start_x, self.start_y)
+            }
+        };
+
+        self.prev_x = x;
+        self.prev_y = y;
+        self.prev_ctrl = ctrl;
+
+        match curve {
+            Some(curve) => {
+                self.steps = curve.steps(self.tolerance);
+                self.step = 0;
+                self.curve = Some(curve);
+                None
+            }
+            None => Some(FlatToken::LineTo { x, y }),
+        }
+    }
+}
+
+// Converts an arc from the endpoint to the center parameterization.
+//
+// https://www.w3.org/TR/SVG/implnote.html#ArcConversionEndpointToCenter
+//
+// Returns `None` when the arc should be treated as a straight line.
+fn arc_to_curve(
+    (x1, y1): (f64, f64),
+    (x2, y2): (f64, f64),
+    (rx, ry): (f64, f64),
+    x_axis_rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+) -> Option<Curve> {
+    let mut rx = rx.abs();
+    let mut ry = ry.abs();
+
+    // 'If rx = 0 or ry = 0, then treat this as a straight line segment.'
+    if rx == 0.0 || ry == 0.0 {
+        return None;
+    }
+
+    let (sin_phi, cos_phi) = x_axis_rotation.to_radians().sin_cos();
+
+    let dx2 = (x1 - x2) / 2.0;
+    let dy2 = (y1 - y2) / 2.0;
+    let x1p = cos_phi * dx2 + sin_phi * dy2;
+    let y1p = -sin_phi * dx2 + cos_phi * dy2;
+
+    // Correct out-of-range radii.
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        let l = lambda.sqrt();
+        rx *= l;
+        ry *= l;
+    }
+
+    let rx2 = rx * rx;
+    let ry2 = ry * ry;
+    let num = rx2 * ry2 - rx2 * y1p * y1p - ry2 * x1p * x1p;
+    let den = rx2 * y1p * y1p + ry2 * x1p * x1p;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+
+    let cxp = coef * rx * y1p / ry;
+    let cyp = -coef * ry * x1p / rx;
+
+    let cx = cos_phi * cxp - sin_phi * cyp + (x1 + x2) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (y1 + y2) / 2.0;
+
+    fn angle(ux: f64, uy: f64, vx: f64, vy: f64) -> f64 {
+        (ux * vy - uy * vx).atan2(ux * vx + uy * vy)
+    }
+
+    let ux = (x1p - cxp) / rx;
+    let uy = (y1p - cyp) / ry;
+    let vx = (-x1p - cxp) / rx;
+    let vy = (-y1p - cyp) / ry;
+
+    let theta = angle(1.0, 0.0, ux, uy);
+    let mut delta = angle(ux, uy, vx, vy);
+    if !sweep && delta > 0.0 {
+        delta -= 2.0 * f64::consts::PI;
+    } else if sweep && delta < 0.0 {
+        delta += 2.0 * f64::consts::PI;
+    }
+
+    Some(Curve::Arc { cx, cy, rx, ry, sin_phi, cos_phi, theta, delta })
+}
+
 /// Returns `true` if the selected char is the command.
 pub fn is_cmd(c: u8) -> bool {
     match c {
@@ -379,22 +945,21 @@ fn is_digit(c: u8) -> bool {
 
 // By the SVG spec 'large-arc' and 'sweep' must contain only one char
 // and can be written without any separators, aka: 10 20 30 01 10 20.
-fn parse_flag(s: &mut Stream) -> Option<bool> {
+fn parse_flag(s: &mut Stream) -> StreamResult<bool> {
     s.skip_spaces();
-    let c = try_opt!(s.curr_byte().ok());
+    let c = s.curr_byte()?;
     match c {
         b'0' | b'1' => {
             s.advance(1);
-            if try_opt!(s.curr_byte().ok()) == b',' {
+            if s.is_curr_byte_eq(b',') {
                 s.advance(1);
             }
             s.skip_spaces();
 
-            Some(c == b'1')
+            Ok(c == b'1')
         }
         _ => {
-            // error type is not relevant since it will be ignored
-            None
+            Err(StreamError::InvalidArcFlag(s.gen_error_pos()))
         }
     }
 }
@@ -31,6 +31,27 @@ impl<'a> FromSpan<'a> for Points<'a> {
     }
 }
 
+impl<'a> Points<'a> {
+    /// Returns the number of points without consuming the tokenizer.
+    ///
+    /// Follows the same rules as the iteration, so an incomplete trailing pair
+    /// and anything after an invalid character are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::Points;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let pts = Points::from_str("1,2 3,4 5");
+    /// assert_eq!(pts.count_points(), 2);
+    /// assert_eq!(pts.count(), 2);
+    /// ```
+    pub fn count_points(&self) -> usize {
+        (*self).count()
+    }
+}
+
 impl<'a> fmt::Debug for Points<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Points({:?})", self.0.span())
@@ -58,15 +79,28 @@ impl<'a> Iterator for Points<'a> {
         } else {
             let x = match self.0.parse_list_number() {
                 Ok(x) => x,
-                Err(_) => return None,
+                Err(_) => {
+                    self.0.jump_to_end();
+                    return None;
+                }
             };
 
             let y = match self.0.parse_list_number() {
                 Ok(y) => y,
-                Err(_) => return None,
+                Err(_) => {
+                    self.0.jump_to_end();
+                    return None;
+                }
             };
 
             Some((x, y))
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A point takes at least three bytes, like `1,2`,
+        // and all of them, except the last one, are followed by a separator.
+        let mut s = self.0;
+        (0, Some((s.slice_tail().to_str().len() + 1) / 4))
+    }
 }
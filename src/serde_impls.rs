@@ -0,0 +1,127 @@
+// Copyright 2018 Evgeniy Reizner
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `serde` support for the simple value types.
+//!
+//! `Color`, `Length` and `AspectRatio` are stored in their SVG text form
+//! and parsed back using the same parsers as the attribute values.
+//! `ViewBox` is stored as a sequence of four numbers.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{
+    self,
+    Deserialize,
+    Deserializer,
+    Visitor,
+};
+use serde::ser::{
+    Serialize,
+    Serializer,
+    SerializeTuple,
+};
+use {
+    AspectRatio,
+    Color,
+    Length,
+    ViewBox,
+};
+
+struct StrVisitor<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Option<T>,
+}
+
+impl<'de, T> Visitor<'de> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match (self.parse)(v) {
+            Some(v) => Ok(v),
+            None => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a color",
+            parse: |v| Color::from_str(v).ok(),
+        })
+    }
+}
+
+
+impl Serialize for Length {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Length {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a length",
+            parse: |v| Length::from_str(v).ok(),
+        })
+    }
+}
+
+
+impl Serialize for AspectRatio {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AspectRatio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a preserveAspectRatio value",
+            parse: |v| AspectRatio::from_str(v).ok(),
+        })
+    }
+}
+
+
+impl Serialize for ViewBox {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut t = serializer.serialize_tuple(4)?;
+        t.serialize_element(&self.x)?;
+        t.serialize_element(&self.y)?;
+        t.serialize_element(&self.w)?;
+        t.serialize_element(&self.h)?;
+        t.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewBox {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y, w, h) = <(f64, f64, f64, f64)>::deserialize(deserializer)?;
+
+        // Same check as in the `viewBox` attribute parser.
+        if w <= 0.0 || h <= 0.0 {
+            return Err(de::Error::custom("viewBox should have a positive size"));
+        }
+
+        Ok(ViewBox::new(x, y, w, h))
+    }
+}
@@ -11,6 +11,7 @@ use std::str::FromStr;
 use xmlparser::{
     self,
     Stream,
+    StrSpan,
     XmlByteExt,
 };
 
@@ -19,13 +20,14 @@ use error::{
     StreamResult,
 };
 use {
+    f64_bound,
     Length,
     LengthUnit,
 };
 
 
 /// `Stream` additional methods.
-pub trait StreamExt {
+pub trait StreamExt<'a> {
     /// Parses number from the stream.
     ///
     /// This method will detect a number length and then
@@ -66,6 +68,43 @@ pub trait StreamExt {
     /// ```
     fn parse_list_number(&mut self) -> StreamResult<f64>;
 
+    /// Parses numbers from the list of numbers into the `out` buffer.
+    ///
+    /// Stops at the end of the stream or when the buffer is full.
+    /// Returns the number of parsed numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// let mut buf = [0.0; 2];
+    /// let mut s = Stream::from_str("2.5 3");
+    /// assert_eq!(s.parse_numbers_into(&mut buf).unwrap(), 2);
+    /// assert_eq!(buf, [2.5, 3.0]);
+    /// ```
+    fn parse_numbers_into(&mut self, out: &mut [f64]) -> StreamResult<usize>;
+
+    /// Checks that a [`<number>`] starts at the current position.
+    ///
+    /// Only the beginning of a number is checked: an optional sign,
+    /// an optional dot and a digit. Leading spaces are not skipped.
+    /// The stream is not advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// assert!(Stream::from_str("-.5").peek_is_number());
+    /// assert!(!Stream::from_str("e2").peek_is_number());
+    /// ```
+    ///
+    /// [`<number>`]: https://www.w3.org/TR/SVG/types.html#DataTypeNumber
+    fn peek_is_number(&self) -> bool;
+
     /// Parses integer number from the stream.
     ///
     /// Same as [`parse_number()`], but only for integer. Does not refer to any SVG type.
@@ -95,14 +134,185 @@ pub trait StreamExt {
     /// - Suffix must be lowercase, otherwise it will be an error.
     fn parse_length(&mut self) -> StreamResult<Length>;
 
+    /// Parses length from the stream and returns it along with the number of consumed bytes.
+    ///
+    /// Leading spaces are counted too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::{StreamExt, Length, LengthUnit};
+    ///
+    /// let mut s = Stream::from_str("30% 5");
+    /// assert_eq!(s.parse_length_measured().unwrap(), (Length::new(30.0, LengthUnit::Percent), 3));
+    /// ```
+    fn parse_length_measured(&mut self) -> StreamResult<(Length, usize)>;
+
+    /// Parses length from the stream and checks that it's followed
+    /// by a space, a comma or the end of the stream.
+    ///
+    /// Unlike `parse_length`, an unknown unit, like `5foo`, is an error.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `InvalidLength` at the position of the unexpected byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::{StreamExt, Length, LengthUnit};
+    ///
+    /// let mut s = Stream::from_str("5px");
+    /// assert_eq!(s.parse_length_strict().unwrap(), Length::new(5.0, LengthUnit::Px));
+    ///
+    /// let mut s = Stream::from_str("5foo");
+    /// assert_eq!(s.parse_length_strict().unwrap_err().to_string(), "invalid length at 1:2");
+    /// ```
+    fn parse_length_strict(&mut self) -> StreamResult<Length>;
+
     /// Parses length from the list of lengths.
     fn parse_list_length(&mut self) -> StreamResult<Length>;
 
+    /// Parses lengths from the list of lengths into the `out` buffer.
+    ///
+    /// Stops at the end of the stream or when the buffer is full.
+    /// Returns the number of parsed lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::{StreamExt, Length, LengthUnit};
+    ///
+    /// let mut buf = [Length::new(0.0, LengthUnit::None); 2];
+    /// let mut s = Stream::from_str("3 5mm");
+    /// assert_eq!(s.parse_lengths_into(&mut buf).unwrap(), 2);
+    /// assert_eq!(buf, [Length::new(3.0, LengthUnit::None), Length::new(5.0, LengthUnit::Mm)]);
+    /// ```
+    fn parse_lengths_into(&mut self, out: &mut [Length]) -> StreamResult<usize>;
+
+    /// Parses a number or a percentage from the stream.
+    ///
+    /// A percentage will be divided by 100. The result is not bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// assert_eq!(Stream::from_str("0.5").parse_number_or_percent().unwrap(), 0.5);
+    /// assert_eq!(Stream::from_str("150%").parse_number_or_percent().unwrap(), 1.5);
+    /// ```
+    fn parse_number_or_percent(&mut self) -> StreamResult<f64>;
+
+    /// Parses an opacity value from the stream.
+    ///
+    /// The same as `parse_number_or_percent`, but the result is bound to the 0..1 range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// assert_eq!(Stream::from_str("0.5").parse_opacity().unwrap(), 0.5);
+    /// assert_eq!(Stream::from_str("50%").parse_opacity().unwrap(), 0.5);
+    /// assert_eq!(Stream::from_str("150%").parse_opacity().unwrap(), 1.0);
+    /// ```
+    fn parse_opacity(&mut self) -> StreamResult<f64>;
+
     /// Skips digits.
     fn skip_digits(&mut self);
+
+    /// Skips spaces and CSS comments: `/* */`.
+    ///
+    /// Should be used only for the `style` attribute,
+    /// since other attributes do not support comments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnexpectedEndOfStream` on an unclosed comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// let mut s = Stream::from_str(" /*a*/ /*b*/fill");
+    /// s.skip_spaces_and_comments().unwrap();
+    /// assert_eq!(s.slice_tail().to_str(), "fill");
+    /// ```
+    fn skip_spaces_and_comments(&mut self) -> StreamResult<()>;
+
+    /// Parses a string quoted with `'` or `"`.
+    ///
+    /// Returns the string span without quotes.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `InvalidChar` if the current byte is not a quote.
+    /// - Returns `UnexpectedEndOfStream` if the closing quote is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// let mut s = Stream::from_str("'a \"b\"' c");
+    /// assert_eq!(s.parse_quoted_string().unwrap().to_str(), "a \"b\"");
+    /// assert_eq!(s.slice_tail().to_str(), " c");
+    /// ```
+    fn parse_quoted_string(&mut self) -> StreamResult<StrSpan<'a>>;
+
+    /// Consumes `n` bytes and returns them.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `UnexpectedEndOfStream` if there are less than `n` bytes left.
+    /// - Returns `InvalidChar` if the `n`th byte is in the middle of a UTF-8 character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// let mut s = Stream::from_str("Some text");
+    /// assert_eq!(s.take(4).unwrap(), "Some");
+    /// assert_eq!(s.slice_tail().to_str(), " text");
+    /// ```
+    fn take(&mut self, n: usize) -> StreamResult<&'a str>;
+
+    /// Consumes a group of balanced parentheses and returns its content.
+    ///
+    /// The current byte must be `(`. Nested groups are included in the content.
+    /// Quotes are not processed, so a parenthesis inside a string is still counted.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `InvalidChar` if the current byte is not `(`.
+    /// - Returns `UnexpectedEndOfStream` if the group is not closed.
+    ///   The stream is not advanced in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::Stream;
+    /// use svgparser::StreamExt;
+    ///
+    /// let mut s = Stream::from_str("(a(b)c) d");
+    /// assert_eq!(s.consume_balanced_parens().unwrap(), "a(b)c");
+    /// assert_eq!(s.slice_tail().to_str(), " d");
+    /// ```
+    fn consume_balanced_parens(&mut self) -> StreamResult<&'a str>;
 }
 
-impl<'a> StreamExt for Stream<'a> {
+impl<'a> StreamExt<'a> for Stream<'a> {
     fn parse_number(&mut self) -> StreamResult<f64> {
         // strip off leading blanks
         self.skip_spaces();
@@ -191,6 +401,8 @@ impl<'a> StreamExt for Stream<'a> {
     }
 
     fn parse_list_number(&mut self) -> StreamResult<f64> {
+        self.skip_spaces();
+
         if self.at_end() {
             return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
         }
@@ -201,6 +413,40 @@ impl<'a> StreamExt for Stream<'a> {
         Ok(n)
     }
 
+    fn parse_numbers_into(&mut self, out: &mut [f64]) -> StreamResult<usize> {
+        let mut count = 0;
+        for n in out.iter_mut() {
+            self.skip_spaces();
+            if self.at_end() {
+                break;
+            }
+
+            *n = self.parse_list_number()?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    fn peek_is_number(&self) -> bool {
+        let mut s = *self;
+        let tail = s.slice_tail().to_str().as_bytes();
+
+        let mut i = 0;
+        if tail.first() == Some(&b'+') || tail.first() == Some(&b'-') {
+            i += 1;
+        }
+
+        if tail.get(i) == Some(&b'.') {
+            i += 1;
+        }
+
+        match tail.get(i) {
+            Some(c) => c.is_xml_digit(),
+            None => false,
+        }
+    }
+
     fn parse_integer(&mut self) -> StreamResult<i32> {
         self.skip_spaces();
 
@@ -238,6 +484,8 @@ impl<'a> StreamExt for Stream<'a> {
     }
 
     fn parse_list_integer(&mut self) -> StreamResult<i32> {
+        self.skip_spaces();
+
         if self.at_end() {
             return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
         }
@@ -288,7 +536,26 @@ impl<'a> StreamExt for Stream<'a> {
         Ok(Length::new(n, u))
     }
 
+    fn parse_length_measured(&mut self) -> StreamResult<(Length, usize)> {
+        let start = self.pos();
+        let l = self.parse_length()?;
+        Ok((l, self.pos() - start))
+    }
+
+    fn parse_length_strict(&mut self) -> StreamResult<Length> {
+        let l = self.parse_length()?;
+
+        match self.get_curr_byte() {
+            Some(c) if !c.is_xml_space() && c != b',' => {
+                Err(StreamError::InvalidLength(self.gen_error_pos()))
+            }
+            _ => Ok(l),
+        }
+    }
+
     fn parse_list_length(&mut self) -> StreamResult<Length> {
+        self.skip_spaces();
+
         if self.at_end() {
             return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
         }
@@ -299,9 +566,127 @@ impl<'a> StreamExt for Stream<'a> {
         Ok(l)
     }
 
+    fn parse_lengths_into(&mut self, out: &mut [Length]) -> StreamResult<usize> {
+        let mut count = 0;
+        for l in out.iter_mut() {
+            self.skip_spaces();
+            if self.at_end() {
+                break;
+            }
+
+            *l = self.parse_list_length()?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    fn parse_number_or_percent(&mut self) -> StreamResult<f64> {
+        let mut n = self.parse_number()?;
+
+        if self.is_curr_byte_eq(b'%') {
+            self.advance(1);
+            n /= 100.0;
+        }
+
+        Ok(n)
+    }
+
+    fn parse_opacity(&mut self) -> StreamResult<f64> {
+        let n = self.parse_number_or_percent()?;
+        Ok(f64_bound(0.0, n, 1.0))
+    }
+
     fn skip_digits(&mut self) {
         self.skip_bytes(|_, c| c.is_xml_digit());
     }
+
+    fn skip_spaces_and_comments(&mut self) -> StreamResult<()> {
+        self.skip_spaces();
+        while self.starts_with(b"/*") {
+            self.advance(2);
+
+            // A comment can contain '*', so we have to look for '*/' explicitly.
+            loop {
+                self.skip_bytes(|_, c| c != b'*');
+                if self.at_end() {
+                    return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
+                }
+
+                if self.starts_with(b"*/") {
+                    self.advance(2);
+                    break;
+                }
+
+                self.advance(1);
+            }
+
+            self.skip_spaces();
+        }
+
+        Ok(())
+    }
+
+    fn parse_quoted_string(&mut self) -> StreamResult<StrSpan<'a>> {
+        let quote = self.curr_byte()?;
+        if quote != b'\'' && quote != b'"' {
+            let pos = self.gen_error_pos();
+            return Err(xmlparser::StreamError::InvalidChar(quote as char, "'\"".into(), pos).into());
+        }
+
+        self.advance(1);
+        let text = self.consume_bytes(|_, c| c != quote);
+        self.consume_byte(quote)?;
+
+        Ok(text)
+    }
+
+    fn take(&mut self, n: usize) -> StreamResult<&'a str> {
+        let tail = self.slice_tail().to_str();
+        if n > tail.len() {
+            return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
+        }
+
+        // Check that we will not split a character.
+        if !tail.is_char_boundary(n) {
+            // Find the start of the character that will be split.
+            let mut idx = n - 1;
+            while !tail.is_char_boundary(idx) {
+                idx -= 1;
+            }
+
+            let c = tail[idx..].chars().next().unwrap();
+            let pos = self.gen_error_pos_from(self.pos() + idx);
+            return Err(xmlparser::StreamError::InvalidChar(c, "a char boundary".into(), pos).into());
+        }
+
+        self.advance(n);
+        Ok(&tail[..n])
+    }
+
+    fn consume_balanced_parens(&mut self) -> StreamResult<&'a str> {
+        let mut s = *self;
+        s.consume_byte(b'(')?;
+
+        let tail = s.slice_tail().to_str();
+        let mut depth = 1;
+        for (i, c) in tail.bytes().enumerate() {
+            match c {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        s.advance(i + 1);
+                        *self = s;
+                        return Ok(&tail[..i]);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Err(xmlparser::StreamError::UnexpectedEndOfStream.into())
+    }
 }
 
 #[inline]
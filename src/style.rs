@@ -24,18 +24,25 @@ use xmlparser::{
 use error::{
     StreamError,
     StreamResult,
+    StyleError,
 };
 use {
     AttributeId,
+    AttributeValue,
+    ElementId,
+    StreamExt,
 };
 
 /// Style token.
 #[derive(PartialEq)]
 pub enum Token<'a> {
-    /// Tuple contains attribute's name and value of an XML element.
-    XmlAttribute(&'a str, &'a str),
-    /// Tuple contains attribute's ID and value of an SVG element.
-    SvgAttribute(AttributeId, StrSpan<'a>),
+    /// Tuple contains attribute's name, value and `!important` flag of an XML element.
+    ///
+    /// An unquoted value is trimmed, but its internal whitespace is preserved.
+    /// A quoted value is returned verbatim, without quotes.
+    XmlAttribute(&'a str, &'a str, bool),
+    /// Tuple contains attribute's ID, value and `!important` flag of an SVG element.
+    SvgAttribute(AttributeId, StrSpan<'a>, bool),
     /// Tuple contains ENTITY reference. Just a name without `&` and `;`.
     EntityRef(&'a str),
 }
@@ -43,10 +50,10 @@ pub enum Token<'a> {
 impl<'a> fmt::Debug for Token<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Token::XmlAttribute(name, ref value) =>
-                write!(f, "XmlAttribute({}, {})", name, value),
-            Token::SvgAttribute(id, ref value) =>
-                write!(f, "SvgAttribute({:?}, {:?})", id, value),
+            Token::XmlAttribute(name, ref value, important) =>
+                write!(f, "XmlAttribute({}, {}, {})", name, value, important),
+            Token::SvgAttribute(id, ref value, important) =>
+                write!(f, "SvgAttribute({:?}, {:?}, {})", id, value, important),
             Token::EntityRef(name) =>
                 write!(f, "EntityRef({})", name),
         }
@@ -67,6 +74,41 @@ impl<'a> FromSpan<'a> for Tokenizer<'a> {
     }
 }
 
+impl<'a> Tokenizer<'a> {
+    /// Returns the data that was not parsed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::style::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut s = Tokenizer::from_str("fill:red; stroke:blue");
+    /// s.next();
+    /// assert_eq!(s.remaining().to_str(), "stroke:blue");
+    /// ```
+    pub fn remaining(&self) -> StrSpan<'a> {
+        let mut s = self.stream;
+        s.slice_tail()
+    }
+
+    /// Returns a new tokenizer over the same data, positioned at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::style::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut s = Tokenizer::from_str("fill:red; stroke:blue");
+    /// assert_eq!(s.by_ref().count(), 2);
+    /// assert_eq!(s.restart().count(), 2);
+    /// ```
+    pub fn restart(&self) -> Self {
+        Tokenizer::from_span(self.stream.span())
+    }
+}
+
 impl<'a> fmt::Debug for Tokenizer<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "StyleTokenizer({:?})", self.stream.span())
@@ -90,34 +132,32 @@ impl<'a> Iterator for Tokenizer<'a> {
     ///   Library will print a warning to stderr.
     /// - All comments are automatically skipped.
     fn next(&mut self) -> Option<Self::Item> {
-        self.stream.skip_spaces();
-
-        if self.stream.at_end() {
-            return None;
-        }
-
         macro_rules! try2 {
             ($expr:expr) => {
                 match $expr {
                     Ok(value) => value,
                     Err(e) => {
+                        self.stream.jump_to_end();
                         return Some(Err(e.into()));
                     }
                 }
             }
         }
 
+        try2!(self.stream.skip_spaces_and_comments());
+
+        if self.stream.at_end() {
+            return None;
+        }
+
         let c = try2!(self.stream.curr_byte());
-        if c == b'/' {
-            try2!(skip_comment(&mut self.stream));
-            self.next()
-        } else if c == b'-' {
+        if c == b'-' {
             try2!(parse_prefix(&mut self.stream));
             self.next()
         } else if c == b'&' {
-            Some(parse_entity_ref(&mut self.stream))
+            Some(Ok(try2!(parse_entity_ref(&mut self.stream))))
         } else if is_ident_char(c) {
-            Some(parse_attribute(&mut self.stream))
+            Some(Ok(try2!(parse_attribute(&mut self.stream))))
         } else {
             let pos = self.stream.gen_error_pos();
             self.stream.jump_to_end();
@@ -126,13 +166,85 @@ impl<'a> Iterator for Tokenizer<'a> {
     }
 }
 
-fn skip_comment(stream: &mut Stream) -> StreamResult<()> {
-    stream.skip_string(b"/*")?;
-    stream.skip_bytes(|_, c| c != b'*');
-    stream.skip_string(b"*/")?;
-    stream.skip_spaces();
+/// Style token with a parsed SVG attribute value.
+#[derive(Debug, PartialEq)]
+pub enum TypedToken<'a> {
+    /// Tuple contains attribute's name, value and `!important` flag of an XML element.
+    XmlAttribute(&'a str, &'a str, bool),
+    /// Tuple contains attribute's ID, parsed value and `!important` flag of an SVG element.
+    SvgAttribute(AttributeId, AttributeValue<'a>, bool),
+    /// Tuple contains ENTITY reference. Just a name without `&` and `;`.
+    EntityRef(&'a str),
+}
 
-    Ok(())
+/// Parses SVG attribute values of the style tokens.
+///
+/// Values are parsed using `AttributeValue::from_span` for the specified element.
+/// XML attributes and entity references are returned as is.
+///
+/// Like the `Tokenizer`, stops after the first error.
+/// An attribute value error is returned as `StyleError::Attribute`,
+/// so it contains the attribute that failed to parse.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::style::{typed, Tokenizer, TypedToken};
+/// use svgparser::xmlparser::FromSpan;
+/// use svgparser::{AttributeId, AttributeValue, Color, ElementId};
+///
+/// let mut s = typed(Tokenizer::from_str("fill:red"), ElementId::Rect);
+/// assert_eq!(s.next().unwrap().unwrap(),
+///            TypedToken::SvgAttribute(AttributeId::Fill,
+///                                     AttributeValue::Color(Color::new(255, 0, 0)), false));
+/// ```
+pub fn typed(tokenizer: Tokenizer, eid: ElementId) -> TypedTokenizer {
+    TypedTokenizer {
+        tokenizer,
+        eid,
+    }
+}
+
+/// An iterator over typed style tokens.
+///
+/// See [`typed`] for details.
+///
+/// [`typed`]: fn.typed.html
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TypedTokenizer<'a> {
+    tokenizer: Tokenizer<'a>,
+    eid: ElementId,
+}
+
+impl<'a> Iterator for TypedTokenizer<'a> {
+    type Item = Result<TypedToken<'a>, StyleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = match try_opt!(self.tokenizer.next()) {
+            Ok(token) => token,
+            Err(e) => return Some(Err(e.into())),
+        };
+
+        let token = match token {
+            Token::XmlAttribute(name, value, important) => {
+                TypedToken::XmlAttribute(name, value, important)
+            }
+            Token::SvgAttribute(aid, value, important) => {
+                match AttributeValue::from_span(self.eid, "", aid, value) {
+                    Ok(v) => TypedToken::SvgAttribute(aid, v, important),
+                    Err(e) => {
+                        self.tokenizer.stream.jump_to_end();
+                        return Some(Err(e.into()));
+                    }
+                }
+            }
+            Token::EntityRef(name) => {
+                TypedToken::EntityRef(name)
+            }
+        };
+
+        Some(Ok(token))
+    }
 }
 
 fn parse_attribute<'a>(stream: &mut Stream<'a>) -> StreamResult<Token<'a>> {
@@ -144,43 +256,89 @@ fn parse_attribute<'a>(stream: &mut Stream<'a>) -> StreamResult<Token<'a>> {
         return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
     }
 
-    stream.skip_spaces();
+    stream.skip_spaces_and_comments()?;
     stream.consume_byte(b':')?;
-    stream.skip_spaces();
+    stream.skip_spaces_and_comments()?;
 
-    let value = if stream.curr_byte()? == b'\'' {
-        stream.advance(1);
-        let v = stream.consume_bytes(|_, c| c != b'\'');
-        stream.consume_byte(b'\'')?;
-        v
+    let c = stream.curr_byte()?;
+    let quoted = if c == b'\'' || c == b'"' {
+        Some(stream.parse_quoted_string()?)
     } else if stream.starts_with(b"&apos;") {
         stream.advance(6);
         let v = stream.consume_bytes(|_, c| c != b'&');
         stream.skip_string(b"&apos;")?;
-        v
+        Some(v)
+    } else if stream.starts_with(b"&quot;") {
+        stream.advance(6);
+        let v = stream.consume_bytes(|_, c| c != b'&');
+        stream.skip_string(b"&quot;")?;
+        Some(v)
     } else {
-        stream.consume_bytes(|_, c| c != b';' && c != b'/')
-    }.trim();
+        None
+    };
+
+    let (value, important) = match quoted {
+        Some(v) => {
+            // Only '!important' is allowed after a quoted value.
+            let mut s = *stream;
+            s.skip_spaces();
+            let tail = s.consume_bytes(|_, c| c != b';' && c != b'/');
+            let (tail, important) = split_important(tail.trim());
+            if important && tail.is_empty() {
+                *stream = s;
+            }
 
-    if value.len() == 0 {
+            (v, important && tail.is_empty())
+        }
+        None => {
+            let v = stream.consume_bytes(|_, c| c != b';' && c != b'/').trim();
+            split_important(v)
+        }
+    };
+
+    if value.trim().is_empty() {
         return Err(xmlparser::StreamError::UnexpectedEndOfStream.into());
     }
 
-    stream.skip_spaces();
+    stream.skip_spaces_and_comments()?;
 
     // ';;;' is valid style data, we need to skip it
     while stream.is_curr_byte_eq(b';') {
         stream.advance(1);
-        stream.skip_spaces();
+        stream.skip_spaces_and_comments()?;
     }
 
     if let Some(aid) = AttributeId::from_name(name.to_str()) {
-        Ok(Token::SvgAttribute(aid, value))
+        Ok(Token::SvgAttribute(aid, value.trim(), important))
     } else {
-        Ok(Token::XmlAttribute(name.to_str(), value.to_str()))
+        // Unknown attributes are preserved as is.
+        Ok(Token::XmlAttribute(name.to_str(), value.to_str(), important))
     }
 }
 
+// Splits 'value !important' into 'value' and a flag.
+fn split_important(value: StrSpan) -> (StrSpan, bool) {
+    const IMPORTANT: &str = "important";
+
+    #[allow(unused_imports)]
+    use std::ascii::AsciiExt;
+
+    let len = value.len();
+    // The keyword is ASCII, so a value that doesn't end with it
+    // can have a multi-byte character at the split position.
+    if len >= IMPORTANT.len() && value.to_str().is_char_boundary(len - IMPORTANT.len()) {
+        let keyword = value.slice_region(len - IMPORTANT.len(), len);
+        if keyword.to_str().eq_ignore_ascii_case(IMPORTANT) {
+            let rest = value.slice_region(0, len - IMPORTANT.len()).trim();
+            if rest.to_str().ends_with('!') {
+                return (rest.slice_region(0, rest.len() - 1).trim(), true);
+            }
+        }
+    }
+
+    (value, false)
+}
+
 fn parse_entity_ref<'a>(stream: &mut Stream<'a>) -> StreamResult<Token<'a>> {
     match stream.consume_reference()? {
         Reference::EntityRef(name) => {
@@ -198,7 +356,7 @@ fn parse_prefix<'a>(stream: &mut Stream<'a>) -> StreamResult<()> {
     stream.advance(1); // -
     let t = parse_attribute(stream)?;
 
-    if let Token::XmlAttribute(name, _) = t {
+    if let Token::XmlAttribute(name, _, _) = t {
         warn!("Style attribute '-{}' is skipped.", name);
     }
 
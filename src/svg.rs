@@ -8,17 +8,23 @@
 
 //! Module for parsing SVG structure.
 
+use std::fmt;
 use std::str;
 
 use xmlparser::{
     self,
     FromSpan,
+    Stream,
     StrSpan,
+    TokenType,
 };
 
 use {
     AttributeId,
+    AttributeValue,
+    Color,
     ElementId,
+    Length,
 };
 
 
@@ -50,6 +56,25 @@ impl<'a, T> QName<'a, T> {
     }
 }
 
+impl<'a, T: fmt::Display> fmt::Display for Name<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Name::Xml(name) => write!(f, "{}", name),
+            Name::Svg(ref id) => write!(f, "{}", id),
+        }
+    }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for QName<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.prefix.is_empty() {
+            write!(f, "{}:", self.prefix)?;
+        }
+
+        write!(f, "{}", self.local)
+    }
+}
+
 /// Tag name.
 pub type TagName<'a> = QName<'a, ElementId>;
 /// Attribute name.
@@ -57,6 +82,9 @@ pub type AttrName<'a> = QName<'a, AttributeId>;
 
 type StrSpanPair<'a> = (StrSpan<'a>, StrSpan<'a>);
 
+/// The SVG namespace URI.
+pub const SVG_NS: &str = "http://www.w3.org/2000/svg";
+
 impl<'a> From<StrSpanPair<'a>> for TagName<'a> {
     fn from(v: StrSpanPair<'a>) -> Self {
         let v1 = v.1.to_str();
@@ -163,17 +191,389 @@ pub enum ElementEnd<'a> {
 }
 
 
+/// An element with all its attributes.
+///
+/// Attribute values are parsed on demand.
+///
+/// Can be created via [`Tokenizer::parse_element`].
+///
+/// [`Tokenizer::parse_element`]: struct.Tokenizer.html#method.parse_element
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element<'a> {
+    /// Element's tag name.
+    pub name: TagName<'a>,
+    /// Element's attributes in the original order.
+    pub attributes: Vec<(AttrName<'a>, StrSpan<'a>)>,
+    /// Element's end. Can be only `Open` or `Empty`.
+    pub end: ElementEnd<'a>,
+}
+
+impl<'a> Element<'a> {
+    /// Returns a raw value of the SVG attribute.
+    pub fn attribute(&self, aid: AttributeId) -> Option<StrSpan<'a>> {
+        for &(ref name, value) in &self.attributes {
+            if name.local == Name::Svg(aid) {
+                return Some(value);
+            }
+        }
+
+        None
+    }
+
+    /// Returns a parsed value of the SVG attribute.
+    ///
+    /// Returns `None` if an attribute is not set or if it has an invalid value.
+    /// Also, non-SVG elements always return `None`.
+    pub fn value(&self, aid: AttributeId) -> Option<AttributeValue<'a>> {
+        let eid = match self.name.local {
+            Name::Svg(eid) => eid,
+            Name::Xml(_) => return None,
+        };
+
+        for &(ref name, value) in &self.attributes {
+            if name.local == Name::Svg(aid) {
+                return AttributeValue::from_span(eid, name.prefix, aid, value).ok();
+            }
+        }
+
+        None
+    }
+
+    /// Returns a `<length>` value of the SVG attribute.
+    ///
+    /// Returns `None` if an attribute is not set or if it has a different type.
+    pub fn length(&self, aid: AttributeId) -> Option<Length> {
+        self.value(aid).and_then(|v| v.as_length())
+    }
+
+    /// Returns a `<number>` value of the SVG attribute.
+    ///
+    /// Returns `None` if an attribute is not set or if it has a different type.
+    pub fn number(&self, aid: AttributeId) -> Option<f64> {
+        self.value(aid).and_then(|v| v.as_number())
+    }
+
+    /// Returns a `<color>` value of the SVG attribute.
+    ///
+    /// Returns `None` if an attribute is not set or if it has a different type,
+    /// like `none` or `url(#id)`.
+    pub fn color(&self, aid: AttributeId) -> Option<Color> {
+        self.value(aid).and_then(|v| v.as_color())
+    }
+}
+
+
+/// A start tag writer.
+///
+/// Collects a tag name and attributes and writes them back to the text.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::{svg, AttributeId, ElementId};
+/// use svgparser::svg::{Name, QName};
+///
+/// let mut tag = svg::StartTagWriter::new(QName::new("", Name::Svg(ElementId::Rect)));
+/// tag.push_attribute(QName::new("", Name::Svg(AttributeId::X)), "1");
+/// assert_eq!(tag.write(true), "<rect x=\"1\"/>");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartTagWriter<'a> {
+    name: TagName<'a>,
+    // The flag indicates that a value is already escaped.
+    attributes: Vec<(AttrName<'a>, &'a str, bool)>,
+}
+
+impl<'a> StartTagWriter<'a> {
+    /// Creates a new `StartTagWriter`.
+    pub fn new(name: TagName<'a>) -> Self {
+        StartTagWriter {
+            name,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Appends an attribute.
+    ///
+    /// `value` should be an unescaped text. `&`, `<` and `"` will be escaped on write.
+    pub fn push_attribute(&mut self, name: AttrName<'a>, value: &'a str) {
+        self.attributes.push((name, value, false));
+    }
+
+    /// Appends an attribute with an already escaped value.
+    ///
+    /// Use it for values returned by the `Tokenizer`, which are not unescaped.
+    /// Only `"` will be escaped on write, since the original value
+    /// could be quoted with `'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::{svg, AttributeId, ElementId};
+    /// use svgparser::svg::{Name, QName};
+    /// use svgparser::xmlparser::StrSpan;
+    ///
+    /// let mut tag = svg::StartTagWriter::new(QName::new("", Name::Svg(ElementId::Text)));
+    /// tag.push_raw_attribute(QName::new("", Name::Xml("data")), StrSpan::from_str("a&amp;b"));
+    /// assert_eq!(tag.write(true), "<text data=\"a&amp;b\"/>");
+    /// ```
+    pub fn push_raw_attribute(&mut self, name: AttrName<'a>, value: StrSpan<'a>) {
+        self.attributes.push((name, value.to_str(), true));
+    }
+
+    /// Writes the tag.
+    ///
+    /// The tag will be closed with `/>` when `is_empty` is set and with `>` otherwise.
+    pub fn write(&self, is_empty: bool) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        write!(out, "<{}", self.name).unwrap();
+
+        for &(ref name, value, is_escaped) in &self.attributes {
+            write!(out, " {}=\"", name).unwrap();
+            for c in value.chars() {
+                match c {
+                    '&' if !is_escaped => out.push_str("&amp;"),
+                    '<' if !is_escaped => out.push_str("&lt;"),
+                    '"' => out.push_str("&quot;"),
+                    _ => out.push(c),
+                }
+            }
+            out.push('"');
+        }
+
+        out.push_str(if is_empty { "/>" } else { ">" });
+        out
+    }
+}
+
+
+/// The UTF-8 byte order mark.
+const BOM: &str = "\u{FEFF}";
+
+/// Checks that the text starts with a UTF-8 byte order mark.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::svg::has_bom;
+///
+/// assert!(has_bom("\u{FEFF}<svg/>"));
+/// assert!(!has_bom("<svg/>"));
+/// ```
+pub fn has_bom(text: &str) -> bool {
+    text.starts_with(BOM)
+}
+
+
 /// Tokenizer for SVG structure.
+///
+/// A UTF-8 byte order mark at the start of the input is skipped.
 #[allow(missing_debug_implementations)]
 pub struct Tokenizer<'a> {
     parser: xmlparser::Tokenizer<'a>,
+    text: StrSpan<'a>,
+    svg_prefixes: Option<&'a [&'a str]>,
+    max_attributes: Option<usize>,
+    attributes_count: usize,
+    bytes_consumed: usize,
+    open_elements: Option<Vec<TagName<'a>>>,
+    closing_tag_mismatch: Option<(TagName<'a>, TagName<'a>)>,
 }
 
 impl<'a> FromSpan<'a> for Tokenizer<'a> {
     fn from_span(span: StrSpan<'a>) -> Self {
+        // Skip BOM explicitly instead of relying on the underlying tokenizer.
+        let xml_span = if has_bom(span.to_str()) {
+            span.slice_region(BOM.len(), span.len())
+        } else {
+            span
+        };
+
         Tokenizer {
-            parser: xmlparser::Tokenizer::from_span(span),
+            parser: xmlparser::Tokenizer::from_span(xml_span),
+            text: span,
+            svg_prefixes: None,
+            max_attributes: None,
+            attributes_count: 0,
+            bytes_consumed: 0,
+            open_elements: None,
+            closing_tag_mismatch: None,
+        }
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Enables namespace-aware tag names resolution.
+    ///
+    /// `prefixes` is a list of namespace prefixes that are bound to the [`SVG_NS`]
+    /// by the `xmlns` declarations. An empty prefix indicates that the default namespace
+    /// is the SVG one.
+    ///
+    /// By default, tag names are resolved ignoring the prefix, so `foo:rect` will be
+    /// parsed as `ElementId::Rect`. After this method was called, only elements with
+    /// a listed prefix will be resolved into `Name::Svg`. Other will be `Name::Xml`.
+    ///
+    /// The tokenizer doesn't process `xmlns` attributes by itself, since they are
+    /// emitted after the `ElementStart` token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::svg::{Tokenizer, Token, Name};
+    /// use svgparser::xmlparser::FromSpan;
+    /// use svgparser::ElementId;
+    ///
+    /// let mut p = Tokenizer::from_str("<svg:rect/>");
+    /// p.set_svg_prefixes(&["svg"]);
+    /// match p.next().unwrap().unwrap() {
+    ///     Token::ElementStart(tag) => assert_eq!(tag.local, Name::Svg(ElementId::Rect)),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// [`SVG_NS`]: constant.SVG_NS.html
+    pub fn set_svg_prefixes(&mut self, prefixes: &'a [&'a str]) {
+        self.svg_prefixes = Some(prefixes);
+    }
+
+    /// Sets the maximum number of attributes per element.
+    ///
+    /// When an element has more attributes than `count`, an
+    /// `Error::UnexpectedToken(TokenType::Attribute, _)` will be returned
+    /// for each extra attribute.
+    ///
+    /// Useful to protect from a malicious input. No limit by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::svg::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut p = Tokenizer::from_str("<rect x='1' y='2'/>");
+    /// p.set_max_attributes(1);
+    /// assert!(p.next().unwrap().is_ok()); // ElementStart
+    /// assert!(p.next().unwrap().is_ok()); // x
+    /// assert!(p.next().unwrap().is_err()); // y
+    /// ```
+    pub fn set_max_attributes(&mut self, count: usize) {
+        self.max_attributes = Some(count);
+    }
+
+    /// Enables closing tags checking.
+    ///
+    /// The underlying tokenizer doesn't check that a closing tag matches the open one.
+    /// After this method was called, the tokenizer will track open elements
+    /// and return an `Error::UnexpectedToken(TokenType::ElementClose, _)` on a mismatch.
+    ///
+    /// Both tag names can be retrieved via [`closing_tag_mismatch`] afterwards.
+    ///
+    /// [`closing_tag_mismatch`]: #method.closing_tag_mismatch
+    pub fn set_check_closing_tags(&mut self) {
+        self.open_elements = Some(Vec::new());
+    }
+
+    /// Returns the expected and the actual tag names of the last mismatched closing tag.
+    ///
+    /// Always `None` when closing tags checking is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::svg::{Tokenizer, Name};
+    /// use svgparser::xmlparser::FromSpan;
+    /// use svgparser::ElementId;
+    ///
+    /// let mut p = Tokenizer::from_str("<svg></g>");
+    /// p.set_check_closing_tags();
+    /// assert!(p.next().unwrap().is_ok()); // <svg
+    /// assert!(p.next().unwrap().is_ok()); // >
+    /// assert!(p.next().unwrap().is_err()); // </g>
+    ///
+    /// let (expected, actual) = p.closing_tag_mismatch().unwrap();
+    /// assert_eq!(expected.local, Name::Svg(ElementId::Svg));
+    /// assert_eq!(actual.local, Name::Svg(ElementId::G));
+    /// ```
+    pub fn closing_tag_mismatch(&self) -> Option<(TagName<'a>, TagName<'a>)> {
+        self.closing_tag_mismatch
+    }
+
+    /// Returns the number of bytes consumed so far.
+    ///
+    /// The value is an offset, relative to the start of the input, of the end
+    /// of the last token data. Closing delimiters, like `>` or `"`, are not counted.
+    ///
+    /// Returns `0` before the first token.
+    ///
+    /// Useful for a progress reporting on large files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::svg::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut p = Tokenizer::from_str("<svg/>");
+    /// assert_eq!(p.bytes_consumed(), 0);
+    /// p.next();
+    /// assert_eq!(p.bytes_consumed(), 4);
+    /// ```
+    pub fn bytes_consumed(&self) -> usize {
+        self.bytes_consumed
+    }
+
+    /// Parses all attributes of the current element.
+    ///
+    /// Should be called right after the `ElementStart` token with its `name`.
+    /// Consumes all tokens up to and including the `ElementEnd`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::svg::{Tokenizer, Token};
+    /// use svgparser::xmlparser::FromSpan;
+    /// use svgparser::{AttributeId, Length, LengthUnit};
+    ///
+    /// let mut p = Tokenizer::from_str("<rect x='10' fill='none'/>");
+    /// let name = match p.next().unwrap().unwrap() {
+    ///     Token::ElementStart(name) => name,
+    ///     _ => unreachable!(),
+    /// };
+    /// let elem = p.parse_element(name).unwrap();
+    /// assert_eq!(elem.length(AttributeId::X), Some(Length::new(10.0, LengthUnit::None)));
+    /// assert_eq!(elem.color(AttributeId::Fill), None);
+    /// ```
+    pub fn parse_element(&mut self, name: TagName<'a>) -> Result<Element<'a>, xmlparser::Error> {
+        let mut attributes = Vec::new();
+        for token in self.by_ref() {
+            match token? {
+                Token::Attribute(aname, value) => {
+                    attributes.push((aname, value));
+                }
+                Token::ElementEnd(end) => {
+                    return Ok(Element { name, attributes, end });
+                }
+                _ => break,
+            }
+        }
+
+        // The underlying tokenizer doesn't report an unclosed element.
+        let mut s = Stream::from_span(self.text);
+        s.jump_to_end();
+        Err(xmlparser::Error::InvalidToken(TokenType::ElementStart, s.gen_error_pos()))
+    }
+
+    fn tag_name(&self, prefix: StrSpan<'a>, local: StrSpan<'a>) -> TagName<'a> {
+        if let Some(prefixes) = self.svg_prefixes {
+            let prefix = prefix.to_str();
+            if !prefixes.contains(&prefix) {
+                return QName::new(prefix, Name::Xml(local.to_str()));
+            }
         }
+
+        (prefix, local).into()
     }
 }
 
@@ -197,9 +597,19 @@ impl<'a> Iterator for Tokenizer<'a> {
             Err(e) => return Some(Err(e.into()))
         };
 
+        if let Some(end) = token_end(&token) {
+            self.bytes_consumed = end - self.text.start();
+        }
+
         let t = match token {
             xmlparser::Token::ElementStart(prefix, name) => {
-                Ok(Token::ElementStart((prefix, name).into()))
+                self.attributes_count = 0;
+                let tag_name = self.tag_name(prefix, name);
+                if let Some(ref mut list) = self.open_elements {
+                    list.push(tag_name);
+                }
+
+                Ok(Token::ElementStart(tag_name))
             }
             xmlparser::Token::ElementEnd(end) => {
                 let svg_end = match end {
@@ -207,9 +617,27 @@ impl<'a> Iterator for Tokenizer<'a> {
                         ElementEnd::Open
                     }
                     xmlparser::ElementEnd::Close(prefix, name) => {
-                        ElementEnd::Close((prefix, name).into())
+                        let tag_name = self.tag_name(prefix, name);
+                        if let Some(ref mut list) = self.open_elements {
+                            match list.pop() {
+                                Some(expected) if expected != tag_name => {
+                                    list.push(expected);
+                                    self.closing_tag_mismatch = Some((expected, tag_name));
+                                    let pos = Stream::from_span(name).gen_error_pos();
+                                    let e = xmlparser::Error::UnexpectedToken(TokenType::ElementClose, pos);
+                                    return Some(Err(e));
+                                }
+                                _ => {}
+                            }
+                        }
+
+                        ElementEnd::Close(tag_name)
                     }
                     xmlparser::ElementEnd::Empty => {
+                        if let Some(ref mut list) = self.open_elements {
+                            list.pop();
+                        }
+
                         ElementEnd::Empty
                     }
                 };
@@ -217,6 +645,15 @@ impl<'a> Iterator for Tokenizer<'a> {
                 Ok(Token::ElementEnd(svg_end))
             }
             xmlparser::Token::Attribute(name, value) => {
+                self.attributes_count += 1;
+                if let Some(max) = self.max_attributes {
+                    if self.attributes_count > max {
+                        let span = if name.0.is_empty() { name.1 } else { name.0 };
+                        let pos = Stream::from_span(span).gen_error_pos();
+                        return Some(Err(xmlparser::Error::UnexpectedToken(TokenType::Attribute, pos)));
+                    }
+                }
+
                 Ok(Token::Attribute(name.into(), value))
             }
             xmlparser::Token::Text(text) => {
@@ -264,3 +701,103 @@ impl<'a> Iterator for Tokenizer<'a> {
         Some(t)
     }
 }
+
+/// An iterator over the SVG elements.
+///
+/// A higher-level adapter over [`Tokenizer`] that yields each start tag
+/// with all its attributes. Nested elements are yielded in the document order,
+/// so `<svg><rect/></svg>` produces `svg` and then `rect`.
+///
+/// All other tokens, like text and comments, are skipped.
+/// Iteration stops after the first error.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::svg::{Elements, Name};
+/// use svgparser::xmlparser::FromSpan;
+/// use svgparser::{AttributeId, ElementId};
+///
+/// let mut elements = Elements::from_str("<svg width='10'><rect/></svg>");
+/// let svg = elements.next().unwrap().unwrap();
+/// assert_eq!(svg.name.local, Name::Svg(ElementId::Svg));
+/// assert_eq!(svg.attribute(AttributeId::Width).unwrap().to_str(), "10");
+/// let rect = elements.next().unwrap().unwrap();
+/// assert_eq!(rect.name.local, Name::Svg(ElementId::Rect));
+/// assert!(elements.next().is_none());
+/// ```
+///
+/// [`Tokenizer`]: struct.Tokenizer.html
+#[allow(missing_debug_implementations)]
+pub struct Elements<'a> {
+    tokenizer: Tokenizer<'a>,
+    finished: bool,
+}
+
+impl<'a> FromSpan<'a> for Elements<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        Tokenizer::from_span(span).into()
+    }
+}
+
+/// Allows using a preconfigured tokenizer.
+impl<'a> From<Tokenizer<'a>> for Elements<'a> {
+    fn from(tokenizer: Tokenizer<'a>) -> Self {
+        Elements {
+            tokenizer,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Elements<'a> {
+    type Item = Result<Element<'a>, xmlparser::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            let res = match try_opt!(self.tokenizer.next()) {
+                Ok(Token::ElementStart(name)) => self.tokenizer.parse_element(name),
+                Ok(_) => continue,
+                Err(e) => Err(e),
+            };
+
+            self.finished = res.is_err();
+            return Some(res);
+        }
+    }
+}
+
+// Returns an absolute position of the end of the last span in the token.
+fn token_end(token: &xmlparser::Token) -> Option<usize> {
+    let span = match *token {
+        xmlparser::Token::ElementStart(_, name) => name,
+        xmlparser::Token::ElementEnd(xmlparser::ElementEnd::Close(_, name)) => name,
+        xmlparser::Token::ElementEnd(_) => return None,
+        xmlparser::Token::Attribute(_, value) => value,
+        xmlparser::Token::Text(text) => text,
+        xmlparser::Token::Whitespaces(text) => text,
+        xmlparser::Token::Cdata(text) => text,
+        xmlparser::Token::Comment(text) => text,
+        xmlparser::Token::EntityDeclaration(name, ref def) => {
+            match *def {
+                xmlparser::EntityDefinition::EntityValue(value) => value,
+                _ => name,
+            }
+        }
+        xmlparser::Token::Declaration(version, encoding, standalone) => {
+            standalone.or(encoding).unwrap_or(version)
+        }
+        xmlparser::Token::ProcessingInstruction(target, content) => {
+            content.unwrap_or(target)
+        }
+        xmlparser::Token::DtdStart(name, _) => name,
+        xmlparser::Token::EmptyDtd(name, _) => name,
+        xmlparser::Token::DtdEnd => return None,
+    };
+
+    Some(span.end())
+}
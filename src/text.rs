@@ -0,0 +1,174 @@
+// Copyright 2018 Evgeniy Reizner
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use xmlparser::{
+    self,
+    Reference,
+    Stream,
+    StrSpan,
+    TextUnescape,
+    XmlSpace,
+};
+
+use error::{
+    StreamResult,
+};
+
+
+/// `TextUnescape` additional methods.
+pub trait TextUnescapeExt {
+    /// Converts provided text into an unescaped one and writes it into the `out` buffer.
+    ///
+    /// Same as `TextUnescape::unescape`, but reuses the buffer instead of allocating
+    /// a new `String`. The buffer will be cleared first.
+    ///
+    /// Unlike `TextUnescape`, character references to surrogates and to values
+    /// above `0x10FFFF`, like `&#xD800;`, are not valid and will be kept as is.
+    ///
+    /// Also, with `XmlSpace::Default`, leading and trailing spaces will be removed,
+    /// as described in the [spec].
+    ///
+    /// [spec]: https://www.w3.org/TR/SVG11/text.html#WhiteSpace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::xmlparser::{TextUnescape, XmlSpace};
+    /// use svgparser::TextUnescapeExt;
+    ///
+    /// let mut s = String::new();
+    /// TextUnescape::unescape_into("&gt;", XmlSpace::Default, &mut s);
+    /// assert_eq!(s, ">");
+    /// ```
+    fn unescape_into(text: &str, space: XmlSpace, out: &mut String);
+}
+
+impl<'a> TextUnescapeExt for TextUnescape<'a> {
+    fn unescape_into(text: &str, space: XmlSpace, out: &mut String) {
+        out.clear();
+
+        let preserve_spaces = space == XmlSpace::Preserve;
+        let mut prev = '\0';
+        let mut s = Stream::from_str(text);
+        while !s.at_end() {
+            let mut c = match consume_char_reference(&mut s) {
+                Some(c) => c,
+                None => {
+                    let c = match s.curr_char() {
+                        Ok(c) => c,
+                        Err(_) => break,
+                    };
+                    s.advance(c.len_utf8());
+                    c
+                }
+            };
+
+            // \n and \t should be converted into spaces.
+            if c == '\n' || c == '\t' {
+                c = ' ';
+            }
+
+            // \r should be ignored.
+            if c == '\r' {
+                continue;
+            }
+
+            // Skip leading and continuous spaces when `preserve_spaces` is not set.
+            if !preserve_spaces && c == ' ' && (c == prev || out.is_empty()) {
+                continue;
+            }
+
+            prev = c;
+            out.push(c);
+        }
+
+        // Remove a trailing space when `preserve_spaces` is not set.
+        // There can be only one, since continuous spaces are already collapsed.
+        if !preserve_spaces && prev == ' ' {
+            out.pop();
+        }
+    }
+}
+
+/// Replaces `/* */` comments with spaces.
+///
+/// Comments inside attribute values are supported only by the `style` tokenizer.
+/// This function can be used to allow them in other values, like path or transform data,
+/// when a source is known to produce them.
+///
+/// Each comment character, except a new line, is replaced with a single space,
+/// so an error position in the resulting text is the same as in the original one.
+///
+/// # Errors
+///
+/// Returns `UnexpectedEndOfStream` on an unclosed comment.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::strip_comments;
+/// use svgparser::path::{Tokenizer, Token};
+/// use svgparser::xmlparser::{FromSpan, StrSpan};
+///
+/// let text = strip_comments(StrSpan::from_str("M 10 /*x*/ 20")).unwrap();
+/// assert_eq!(text, "M 10       20");
+///
+/// let mut p = Tokenizer::from_str(&text);
+/// assert_eq!(p.next().unwrap(), Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+/// ```
+pub fn strip_comments(span: StrSpan) -> StreamResult<String> {
+    let mut out = String::with_capacity(span.len());
+    let mut rest = span.to_str();
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // A comment can contain '*', so we have to look for '*/' explicitly.
+        let len = match rest[2..].find("*/") {
+            Some(idx) => idx + 4,
+            None => return Err(xmlparser::StreamError::UnexpectedEndOfStream.into()),
+        };
+
+        for c in rest[..len].chars() {
+            out.push(if c == '\n' { '\n' } else { ' ' });
+        }
+
+        rest = &rest[len..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn consume_char_reference(s: &mut Stream) -> Option<char> {
+    let mut s2 = *s;
+    let c = match s2.consume_reference() {
+        Ok(Reference::CharRef(c)) => c,
+        _ => return None,
+    };
+
+    // `consume_reference` replaces surrogates and values above `0x10FFFF`
+    // with U+FFFD, so we have to check that it was referenced explicitly.
+    if c == '\u{FFFD}' && !is_replacement_char_ref(s2.slice_back(s.pos()).to_str()) {
+        return None;
+    }
+
+    *s = s2;
+    Some(c)
+}
+
+// `&#xFFFD;` or `&#65533;`, with optional leading zeros.
+fn is_replacement_char_ref(text: &str) -> bool {
+    let value = &text[2..text.len() - 1];
+    let n = match value.as_bytes()[0] {
+        b'x' => u32::from_str_radix(&value[1..], 16),
+        _ => value.parse::<u32>(),
+    };
+
+    n == Ok(0xFFFD)
+}
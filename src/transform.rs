@@ -19,6 +19,7 @@ use xmlparser::{
 };
 
 use error::{
+    ErrorSpan,
     StreamError,
     StreamResult,
 };
@@ -56,6 +57,178 @@ pub enum Token {
     },
 }
 
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Matrix { a, b, c, d, e, f: f_ } => {
+                write!(f, "matrix({} {} {} {} {} {})", a, b, c, d, e, f_)
+            }
+            Token::Translate { tx, ty } => {
+                if ty == 0.0 {
+                    write!(f, "translate({})", tx)
+                } else {
+                    write!(f, "translate({} {})", tx, ty)
+                }
+            }
+            Token::Scale { sx, sy } => {
+                if sx == sy {
+                    write!(f, "scale({})", sx)
+                } else {
+                    write!(f, "scale({} {})", sx, sy)
+                }
+            }
+            Token::Rotate { angle } => write!(f, "rotate({})", angle),
+            Token::SkewX { angle } => write!(f, "skewX({})", angle),
+            Token::SkewY { angle } => write!(f, "skewY({})", angle),
+        }
+    }
+}
+
+/// A 2D transformation matrix.
+///
+/// Represents the `matrix(a b c d e f)` transform.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct Matrix {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Matrix {
+    /// Constructs a new `Matrix`.
+    #[inline]
+    pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Matrix {
+        Matrix { a, b, c, d, e, f }
+    }
+
+    /// Constructs an identity `Matrix`.
+    #[inline]
+    pub fn identity() -> Matrix {
+        Matrix::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Checks that the matrix is an identity one.
+    ///
+    /// Values are compared with a small precision, to ignore rounding errors.
+    pub fn is_identity(&self) -> bool {
+        self.approx_eq(&Matrix::identity())
+    }
+
+    /// Multiplies the matrix by `other`.
+    ///
+    /// The result is equal to the `self other` transform list.
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        Matrix {
+            a: self.a * other.a + self.c * other.b,
+            b: self.b * other.a + self.d * other.b,
+            c: self.a * other.c + self.c * other.d,
+            d: self.b * other.c + self.d * other.d,
+            e: self.a * other.e + self.c * other.f + self.e,
+            f: self.b * other.e + self.d * other.f + self.f,
+        }
+    }
+
+    /// Converts the matrix into the most compact transform token.
+    ///
+    /// Values are rounded to 10 decimal places to remove rounding errors
+    /// after matrices multiplication.
+    ///
+    /// Returns `None` for an identity matrix.
+    pub fn to_token(self) -> Option<Token> {
+        let eq = |a: f64, b: f64| fuzzy_eq(a, b);
+
+        if self.is_identity() {
+            return None;
+        }
+
+        let m = Matrix::new(round(self.a), round(self.b), round(self.c),
+                            round(self.d), round(self.e), round(self.f));
+
+        let t = if eq(m.b, 0.0) && eq(m.c, 0.0) {
+            if eq(m.a, 1.0) && eq(m.d, 1.0) {
+                Token::Translate { tx: m.e, ty: m.f }
+            } else if eq(m.e, 0.0) && eq(m.f, 0.0) {
+                Token::Scale { sx: m.a, sy: m.d }
+            } else {
+                m.to_matrix_token()
+            }
+        } else if    eq(m.e, 0.0) && eq(m.f, 0.0)
+                  && eq(m.a, m.d) && eq(m.b, -m.c)
+                  && eq(m.a * m.a + m.b * m.b, 1.0) {
+            Token::Rotate { angle: round(m.b.atan2(m.a).to_degrees()) }
+        } else {
+            m.to_matrix_token()
+        };
+
+        Some(t)
+    }
+
+    fn to_matrix_token(self) -> Token {
+        Token::Matrix { a: self.a, b: self.b, c: self.c, d: self.d, e: self.e, f: self.f }
+    }
+
+    fn approx_eq(&self, other: &Matrix) -> bool {
+           fuzzy_eq(self.a, other.a)
+        && fuzzy_eq(self.b, other.b)
+        && fuzzy_eq(self.c, other.c)
+        && fuzzy_eq(self.d, other.d)
+        && fuzzy_eq(self.e, other.e)
+        && fuzzy_eq(self.f, other.f)
+    }
+}
+
+impl From<Token> for Matrix {
+    fn from(t: Token) -> Self {
+        match t {
+            Token::Matrix { a, b, c, d, e, f } => Matrix::new(a, b, c, d, e, f),
+            Token::Translate { tx, ty } => Matrix::new(1.0, 0.0, 0.0, 1.0, tx, ty),
+            Token::Scale { sx, sy } => Matrix::new(sx, 0.0, 0.0, sy, 0.0, 0.0),
+            Token::Rotate { angle } => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                Matrix::new(cos, sin, -sin, cos, 0.0, 0.0)
+            }
+            Token::SkewX { angle } => {
+                Matrix::new(1.0, 0.0, angle.to_radians().tan(), 1.0, 0.0, 0.0)
+            }
+            Token::SkewY { angle } => {
+                Matrix::new(1.0, angle.to_radians().tan(), 0.0, 1.0, 0.0, 0.0)
+            }
+        }
+    }
+}
+
+// Skips any amount of commas with spaces between transforms.
+//
+// Spaces are consumed only before a comma, so `remaining` will contain them.
+fn skip_separators(s: &mut Stream) {
+    let mut s2 = *s;
+    loop {
+        s2.skip_spaces();
+        if !s2.is_curr_byte_eq(b',') {
+            break;
+        }
+
+        s2.advance(1);
+        *s = s2;
+    }
+}
+
+#[inline]
+fn fuzzy_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-10
+}
+
+#[inline]
+fn round(n: f64) -> f64 {
+    let n = (n * 1e10).round() / 1e10;
+    // Get rid of the negative zero.
+    if n == 0.0 { 0.0 } else { n }
+}
+
 /// Transform tokenizer.
 #[derive(Clone, Copy, PartialEq)]
 pub struct Tokenizer<'a> {
@@ -96,6 +269,175 @@ impl<'a> Iterator for Tokenizer<'a> {
     ///   `translate(<cx> <cy>) rotate(<rotate-angle>) translate(-<cx> -<cy>)`.
     ///   Just like the spec is stated.
     fn next(&mut self) -> Option<Self::Item> {
+        let t = try_opt!(self.next_impl());
+        if t.is_err() {
+            // Stop parsing after an error. Otherwise, we will get the same error forever.
+            self.stream.jump_to_end();
+        }
+
+        Some(t)
+    }
+}
+
+/// Transform tokenizer that yields an accumulated matrix after each token.
+///
+/// Each matrix is a product of all the previous transforms,
+/// so the last one is equal to the `simplify` result.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::transform::{MatrixTokenizer, Matrix};
+/// use svgparser::xmlparser::FromSpan;
+///
+/// let mut ts = MatrixTokenizer::from_str("translate(10 0) scale(2)");
+/// assert_eq!(ts.next().unwrap().unwrap(), Matrix::new(1.0, 0.0, 0.0, 1.0, 10.0, 0.0));
+/// assert_eq!(ts.next().unwrap().unwrap(), Matrix::new(2.0, 0.0, 0.0, 2.0, 10.0, 0.0));
+/// assert!(ts.next().is_none());
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MatrixTokenizer<'a> {
+    tokenizer: Tokenizer<'a>,
+    matrix: Matrix,
+}
+
+impl<'a> FromSpan<'a> for MatrixTokenizer<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        MatrixTokenizer {
+            tokenizer: Tokenizer::from_span(span),
+            matrix: Matrix::identity(),
+        }
+    }
+}
+
+impl<'a> Iterator for MatrixTokenizer<'a> {
+    type Item = StreamResult<Matrix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match try_opt!(self.tokenizer.next()) {
+            Ok(t) => {
+                self.matrix = self.matrix.multiply(&Matrix::from(t));
+                Some(Ok(self.matrix))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Parses all transforms, collecting errors instead of stopping at the first one.
+///
+/// After an error, parsing continues from the next transform function.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::transform::{parse_all_reporting, Token};
+/// use svgparser::xmlparser::StrSpan;
+///
+/// let (tokens, errors) = parse_all_reporting(StrSpan::from_str("scale(2) bad(1) rotate(3)"));
+/// assert_eq!(tokens, vec![Token::Scale { sx: 2.0, sy: 2.0 }, Token::Rotate { angle: 3.0 }]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn parse_all_reporting(span: StrSpan) -> (Vec<Token>, Vec<StreamError>) {
+    let mut tokenizer = Tokenizer::from_span(span);
+    let mut tokens = Vec::new();
+    let mut errors = Vec::new();
+
+    while let Some(t) = tokenizer.next_impl() {
+        match t {
+            Ok(t) => tokens.push(t),
+            Err(e) => {
+                errors.push(e);
+                tokenizer.skip_invalid();
+            }
+        }
+    }
+
+    (tokens, errors)
+}
+
+/// Parses a transform list and composes it into a single matrix.
+///
+/// An empty transform list produces an identity matrix.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::transform::{simplify, Matrix};
+/// use svgparser::xmlparser::StrSpan;
+///
+/// let m = simplify(StrSpan::from_str("translate(10 20) scale(2)")).unwrap();
+/// assert_eq!(m, Matrix::new(2.0, 0.0, 0.0, 2.0, 10.0, 20.0));
+/// ```
+pub fn simplify(span: StrSpan) -> StreamResult<Matrix> {
+    let mut m = Matrix::identity();
+    for t in MatrixTokenizer::from_span(span) {
+        m = t?;
+    }
+
+    Ok(m)
+}
+
+/// Parses a transform list and converts it into the most compact equivalent one.
+///
+/// An identity transform produces an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::transform::simplify_to_string;
+/// use svgparser::xmlparser::StrSpan;
+///
+/// let s = simplify_to_string(StrSpan::from_str("translate(10) translate(5 5)")).unwrap();
+/// assert_eq!(s, "translate(15 5)");
+///
+/// let s = simplify_to_string(StrSpan::from_str("translate(0) scale(1)")).unwrap();
+/// assert_eq!(s, "");
+/// ```
+pub fn simplify_to_string(span: StrSpan) -> StreamResult<String> {
+    let m = simplify(span)?;
+    match m.to_token() {
+        Some(t) => Ok(t.to_string()),
+        None => Ok(String::new()),
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Returns the data that was not parsed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::transform::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut ts = Tokenizer::from_str("scale(2) rotate(45)");
+    /// ts.next();
+    /// assert_eq!(ts.remaining().to_str(), " rotate(45)");
+    /// ```
+    pub fn remaining(&self) -> StrSpan<'a> {
+        let mut s = self.stream;
+        s.slice_tail()
+    }
+
+    /// Returns a new tokenizer over the same data, positioned at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::transform::Tokenizer;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let mut ts = Tokenizer::from_str("scale(2) rotate(45)");
+    /// assert_eq!(ts.by_ref().count(), 2);
+    /// assert_eq!(ts.restart().count(), 2);
+    /// ```
+    pub fn restart(&self) -> Self {
+        Tokenizer::from_span(self.stream.span())
+    }
+
+    // Same as `next`, but doesn't skip the rest of the data after an error.
+    fn next_impl(&mut self) -> Option<StreamResult<Token>> {
         if let Some(a) = self.last_angle {
             self.last_angle = None;
             return Some(Ok(Token::Rotate {
@@ -120,9 +462,20 @@ impl<'a> Iterator for Tokenizer<'a> {
 
         Some(self.parse_next())
     }
-}
 
-impl<'a> Tokenizer<'a> {
+    // Skips the rest of an invalid transform function.
+    fn skip_invalid(&mut self) {
+        let s = &mut self.stream;
+        s.skip_bytes(|_, c| c != b')');
+
+        if s.at_end() {
+            return;
+        }
+
+        s.advance(1); // )
+        skip_separators(s);
+    }
+
     fn parse_next(&mut self) -> StreamResult<Token> {
         let s = &mut self.stream;
 
@@ -208,18 +561,15 @@ impl<'a> Tokenizer<'a> {
                 }
             }
             _ => {
-                let pos = s.gen_error_pos();
-                return Err(StreamError::InvalidTransformPrefix(pos));
+                let span = ErrorSpan::from_stream(s, name.start() - s.span().start(),
+                                                  name.end() - s.span().start());
+                return Err(StreamError::InvalidTransformPrefixSpan(span));
             }
         };
 
         s.skip_spaces();
         s.consume_byte(b')')?;
-        s.skip_spaces();
-
-        if s.is_curr_byte_eq(b',') {
-            s.advance(1);
-        }
+        skip_separators(s);
 
         Ok(t)
     }
@@ -58,6 +58,7 @@ pub enum ValueId {
     Help,
     Hidden,
     Ideographic,
+    Indefinite,
     Inherit,
     Inline,
     InlineTable,
@@ -73,6 +74,7 @@ pub enum ValueId {
     Ltr,
     Marker,
     Mathematical,
+    Media,
     Medium,
     Middle,
     Miter,
@@ -150,170 +152,172 @@ pub enum ValueId {
 }
 
 static VALUES: ::phf::Map<&'static str, ValueId> = ::phf::Map {
-    key: 9603444721912725599,
+    key: 1897749892740154579,
     disps: ::phf::Slice::Static(&[
-        (2, 1),
-        (0, 14),
-        (0, 10),
-        (0, 59),
+        (0, 125),
+        (0, 4),
+        (3, 8),
         (0, 25),
-        (0, 43),
-        (1, 87),
-        (2, 12),
+        (2, 89),
+        (2, 60),
+        (4, 133),
+        (0, 2),
+        (0, 29),
+        (3, 69),
+        (12, 30),
+        (0, 1),
+        (0, 72),
+        (0, 125),
+        (0, 26),
         (0, 0),
-        (0, 79),
-        (1, 35),
-        (1, 32),
-        (2, 3),
-        (3, 83),
-        (0, 45),
-        (1, 0),
+        (6, 31),
         (0, 0),
-        (8, 52),
-        (3, 33),
-        (6, 2),
-        (1, 116),
-        (0, 85),
-        (1, 97),
-        (1, 3),
-        (0, 7),
-        (0, 61),
-        (11, 82),
+        (0, 2),
+        (0, 25),
+        (3, 72),
+        (30, 86),
+        (1, 44),
+        (2, 14),
+        (0, 75),
+        (86, 16),
+        (1, 53),
     ]),
     entries: ::phf::Slice::Static(&[
-        ("200", ValueId::N200),
-        ("bolder", ValueId::Bolder),
-        ("text-before-edge", ValueId::TextBeforeEdge),
-        ("table-column-group", ValueId::TableColumnGroup),
+        ("ultra-expanded", ValueId::UltraExpanded),
+        ("e-resize", ValueId::EResize),
+        ("table-row", ValueId::TableRow),
+        ("normal", ValueId::Normal),
+        ("nonzero", ValueId::Nonzero),
+        ("pointer", ValueId::Pointer),
         ("rl", ValueId::Rl),
+        ("currentColor", ValueId::CurrentColor),
+        ("oblique", ValueId::Oblique),
+        ("300", ValueId::N300),
+        ("wait", ValueId::Wait),
+        ("extra-condensed", ValueId::ExtraCondensed),
+        ("visibleStroke", ValueId::VisibleStroke),
+        ("table-column", ValueId::TableColumn),
+        ("mathematical", ValueId::Mathematical),
+        ("userSpaceOnUse", ValueId::UserSpaceOnUse),
+        ("bolder", ValueId::Bolder),
+        ("table-row-group", ValueId::TableRowGroup),
         ("scroll", ValueId::Scroll),
-        ("reset-size", ValueId::ResetSize),
-        ("800", ValueId::N800),
-        ("embed", ValueId::Embed),
-        ("lighter", ValueId::Lighter),
-        ("stroke", ValueId::Stroke),
-        ("hanging", ValueId::Hanging),
-        ("table", ValueId::Table),
-        ("compact", ValueId::Compact),
-        ("square", ValueId::Square),
-        ("rl-tb", ValueId::RlTb),
-        ("table-row", ValueId::TableRow),
-        ("bevel", ValueId::Bevel),
         ("700", ValueId::N700),
-        ("overline", ValueId::Overline),
-        ("currentColor", ValueId::CurrentColor),
-        ("w-resize", ValueId::WResize),
-        ("table-caption", ValueId::TableCaption),
+        ("baseline", ValueId::Baseline),
+        ("200", ValueId::N200),
+        ("optimizeSpeed", ValueId::OptimizeSpeed),
+        ("stroke", ValueId::Stroke),
+        ("painted", ValueId::Painted),
         ("evenodd", ValueId::Evenodd),
-        ("semi-expanded", ValueId::SemiExpanded),
-        ("large", ValueId::Large),
-        ("userSpaceOnUse", ValueId::UserSpaceOnUse),
-        ("collapse", ValueId::Collapse),
-        ("extra-condensed", ValueId::ExtraCondensed),
-        ("nw-resize", ValueId::NwResize),
-        ("hidden", ValueId::Hidden),
-        ("objectBoundingBox", ValueId::ObjectBoundingBox),
         ("help", ValueId::Help),
-        ("x-small", ValueId::XSmall),
-        ("crispEdges", ValueId::CrispEdges),
-        ("auto", ValueId::Auto),
-        ("painted", ValueId::Painted),
-        ("italic", ValueId::Italic),
-        ("start", ValueId::Start),
-        ("expanded", ValueId::Expanded),
-        ("condensed", ValueId::Condensed),
-        ("tb", ValueId::Tb),
-        ("inherit", ValueId::Inherit),
-        ("use-script", ValueId::UseScript),
-        ("wait", ValueId::Wait),
+        ("inline", ValueId::Inline),
         ("alphabetic", ValueId::Alphabetic),
+        ("n-resize", ValueId::NResize),
+        ("linearRGB", ValueId::LinearRGB),
+        ("bidi-override", ValueId::BidiOverride),
+        ("ne-resize", ValueId::NeResize),
         ("ideographic", ValueId::Ideographic),
-        ("crosshair", ValueId::Crosshair),
-        ("ultra-condensed", ValueId::UltraCondensed),
-        ("marker", ValueId::Marker),
-        ("optimizeQuality", ValueId::OptimizeQuality),
-        ("after-edge", ValueId::AfterEdge),
-        ("visibleStroke", ValueId::VisibleStroke),
-        ("extra-expanded", ValueId::ExtraExpanded),
-        ("inline", ValueId::Inline),
-        ("miter", ValueId::Miter),
-        ("accumulate", ValueId::Accumulate),
-        ("text", ValueId::Text),
-        ("sw-resize", ValueId::SwResize),
-        ("line-through", ValueId::LineThrough),
-        ("round", ValueId::Round),
-        ("e-resize", ValueId::EResize),
-        ("xx-large", ValueId::XxLarge),
-        ("end", ValueId::End),
+        ("reset-size", ValueId::ResetSize),
+        ("900", ValueId::N900),
+        ("text-after-edge", ValueId::TextAfterEdge),
+        ("middle", ValueId::Middle),
+        ("overline", ValueId::Overline),
+        ("nw-resize", ValueId::NwResize),
+        ("small-caps", ValueId::SmallCaps),
+        ("medium", ValueId::Medium),
         ("tb-rl", ValueId::TbRl),
-        ("super", ValueId::Super),
-        ("butt", ValueId::Butt),
-        ("ne-resize", ValueId::NeResize),
-        ("visible", ValueId::Visible),
-        ("geometricPrecision", ValueId::GeometricPrecision),
-        ("100", ValueId::N100),
         ("400", ValueId::N400),
-        ("xx-small", ValueId::XxSmall),
-        ("central", ValueId::Central),
-        ("text-after-edge", ValueId::TextAfterEdge),
+        ("table-footer-group", ValueId::TableFooterGroup),
+        ("large", ValueId::Large),
         ("visibleFill", ValueId::VisibleFill),
-        ("bold", ValueId::Bold),
-        ("optimizeSpeed", ValueId::OptimizeSpeed),
-        ("n-resize", ValueId::NResize),
-        ("bidi-override", ValueId::BidiOverride),
-        ("run-in", ValueId::RunIn),
-        ("list-item", ValueId::ListItem),
-        ("none", ValueId::None),
-        ("repeat", ValueId::Repeat),
-        ("table-column", ValueId::TableColumn),
-        ("small-caps", ValueId::SmallCaps),
+        ("table-cell", ValueId::TableCell),
+        ("collapse", ValueId::Collapse),
+        ("all", ValueId::All),
+        ("media", ValueId::Media),
+        ("indefinite", ValueId::Indefinite),
+        ("tb", ValueId::Tb),
         ("visiblePainted", ValueId::VisiblePainted),
+        ("lr-tb", ValueId::LrTb),
+        ("xx-large", ValueId::XxLarge),
+        ("after-edge", ValueId::AfterEdge),
         ("sRGB", ValueId::SRGB),
-        ("nonzero", ValueId::Nonzero),
-        ("no-change", ValueId::NoChange),
-        ("table-row-group", ValueId::TableRowGroup),
-        ("larger", ValueId::Larger),
-        ("oblique", ValueId::Oblique),
-        ("reflect", ValueId::Reflect),
+        ("100", ValueId::N100),
+        ("bevel", ValueId::Bevel),
+        ("use-script", ValueId::UseScript),
+        ("central", ValueId::Central),
+        ("lighter", ValueId::Lighter),
+        ("x-small", ValueId::XSmall),
+        ("optimizeLegibility", ValueId::OptimizeLegibility),
         ("se-resize", ValueId::SeResize),
-        ("s-resize", ValueId::SResize),
-        ("table-cell", ValueId::TableCell),
+        ("super", ValueId::Super),
         ("sub", ValueId::Sub),
-        ("lr", ValueId::Lr),
-        ("block", ValueId::Block),
-        ("table-footer-group", ValueId::TableFooterGroup),
-        ("small", ValueId::Small),
-        ("middle", ValueId::Middle),
-        ("optimizeLegibility", ValueId::OptimizeLegibility),
         ("x-large", ValueId::XLarge),
-        ("linearRGB", ValueId::LinearRGB),
-        ("medium", ValueId::Medium),
+        ("rtl", ValueId::Rtl),
+        ("hidden", ValueId::Hidden),
+        ("wider", ValueId::Wider),
+        ("start", ValueId::Start),
+        ("expanded", ValueId::Expanded),
+        ("w-resize", ValueId::WResize),
+        ("600", ValueId::N600),
+        ("end", ValueId::End),
+        ("miter", ValueId::Miter),
+        ("larger", ValueId::Larger),
+        ("auto", ValueId::Auto),
+        ("pad", ValueId::Pad),
+        ("butt", ValueId::Butt),
+        ("narrower", ValueId::Narrower),
+        ("no-change", ValueId::NoChange),
+        ("line-through", ValueId::LineThrough),
+        ("crispEdges", ValueId::CrispEdges),
+        ("text-before-edge", ValueId::TextBeforeEdge),
+        ("block", ValueId::Block),
+        ("xx-small", ValueId::XxSmall),
+        ("default", ValueId::Default),
         ("underline", ValueId::Underline),
+        ("round", ValueId::Round),
+        ("list-item", ValueId::ListItem),
+        ("blink", ValueId::Blink),
+        ("text", ValueId::Text),
+        ("crosshair", ValueId::Crosshair),
+        ("smaller", ValueId::Smaller),
+        ("semi-expanded", ValueId::SemiExpanded),
+        ("rl-tb", ValueId::RlTb),
+        ("lr", ValueId::Lr),
+        ("repeat", ValueId::Repeat),
+        ("embed", ValueId::Embed),
+        ("visible", ValueId::Visible),
+        ("ltr", ValueId::Ltr),
         ("inline-table", ValueId::InlineTable),
         ("500", ValueId::N500),
-        ("900", ValueId::N900),
+        ("small", ValueId::Small),
+        ("none", ValueId::None),
+        ("fill", ValueId::Fill),
+        ("inherit", ValueId::Inherit),
+        ("hanging", ValueId::Hanging),
+        ("italic", ValueId::Italic),
+        ("s-resize", ValueId::SResize),
+        ("ultra-condensed", ValueId::UltraCondensed),
+        ("condensed", ValueId::Condensed),
+        ("objectBoundingBox", ValueId::ObjectBoundingBox),
+        ("sw-resize", ValueId::SwResize),
+        ("800", ValueId::N800),
         ("move", ValueId::Move),
-        ("lr-tb", ValueId::LrTb),
-        ("smaller", ValueId::Smaller),
-        ("narrower", ValueId::Narrower),
-        ("ultra-expanded", ValueId::UltraExpanded),
-        ("all", ValueId::All),
-        ("table-header-group", ValueId::TableHeaderGroup),
+        ("extra-expanded", ValueId::ExtraExpanded),
+        ("table-caption", ValueId::TableCaption),
+        ("table-column-group", ValueId::TableColumnGroup),
+        ("run-in", ValueId::RunIn),
+        ("bold", ValueId::Bold),
+        ("geometricPrecision", ValueId::GeometricPrecision),
+        ("optimizeQuality", ValueId::OptimizeQuality),
+        ("reflect", ValueId::Reflect),
         ("before-edge", ValueId::BeforeEdge),
-        ("pointer", ValueId::Pointer),
-        ("default", ValueId::Default),
-        ("normal", ValueId::Normal),
-        ("fill", ValueId::Fill),
-        ("ltr", ValueId::Ltr),
-        ("blink", ValueId::Blink),
-        ("300", ValueId::N300),
-        ("600", ValueId::N600),
-        ("mathematical", ValueId::Mathematical),
-        ("wider", ValueId::Wider),
-        ("baseline", ValueId::Baseline),
-        ("rtl", ValueId::Rtl),
-        ("pad", ValueId::Pad),
+        ("accumulate", ValueId::Accumulate),
+        ("compact", ValueId::Compact),
         ("semi-condensed", ValueId::SemiCondensed),
+        ("marker", ValueId::Marker),
+        ("table", ValueId::Table),
+        ("table-header-group", ValueId::TableHeaderGroup),
+        ("square", ValueId::Square),
     ]),
 };
 
@@ -370,6 +374,7 @@ impl ValueId {
             ValueId::Help => "help",
             ValueId::Hidden => "hidden",
             ValueId::Ideographic => "ideographic",
+            ValueId::Indefinite => "indefinite",
             ValueId::Inherit => "inherit",
             ValueId::Inline => "inline",
             ValueId::InlineTable => "inline-table",
@@ -385,6 +390,7 @@ impl ValueId {
             ValueId::Ltr => "ltr",
             ValueId::Marker => "marker",
             ValueId::Mathematical => "mathematical",
+            ValueId::Media => "media",
             ValueId::Medium => "medium",
             ValueId::Middle => "middle",
             ValueId::Miter => "miter",
@@ -12,12 +12,15 @@ use xmlparser::{
     FromSpan,
     Stream,
     StrSpan,
+    XmlByteExt,
 };
 
 use error::{
+    StreamError,
     StreamResult,
 };
 use {
+    f64_bound,
     Length,
     StreamExt,
 };
@@ -34,21 +37,72 @@ impl<'a> FromSpan<'a> for NumberList<'a> {
     }
 }
 
+impl<'a> NumberList<'a> {
+    /// Collects all numbers into a `Vec`.
+    ///
+    /// Unlike a plain `collect()`, will stop on the first invalid number
+    /// and return its error, which contains the error position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::NumberList;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// assert_eq!(NumberList::from_str("1 2, 3").try_collect().unwrap(), vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(NumberList::from_str("1 2 x 3").try_collect().unwrap_err().to_string(),
+    ///            "invalid number at 1:5");
+    /// ```
+    pub fn try_collect(self) -> StreamResult<Vec<f64>> {
+        self.collect()
+    }
+}
+
 impl<'a> fmt::Debug for NumberList<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "NumberList({:?})", self.0.span())
     }
 }
 
+/// Writes numbers separated by a space.
+///
+/// Stops at the first invalid number.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::NumberList;
+/// use svgparser::xmlparser::FromSpan;
+///
+/// assert_eq!(NumberList::from_str("1,2, 3.5").to_string(), "1 2 3.5");
+/// ```
+impl<'a> fmt::Display for NumberList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_list(*self, f)
+    }
+}
+
 impl<'a> Iterator for NumberList<'a> {
     type Item = StreamResult<f64>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Trailing spaces are not an error.
+        self.0.skip_spaces();
+
         if self.0.at_end() {
-            None
-        } else {
-            Some(self.0.parse_list_number())
+            return None;
         }
+
+        let v = self.0.parse_list_number();
+        if v.is_err() {
+            self.0.jump_to_end();
+        }
+
+        Some(v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(max_list_items(self.0)))
     }
 }
 
@@ -63,6 +117,14 @@ impl<'a> LengthList<'a> {
     pub fn from_span(span: StrSpan<'a>) -> LengthList<'a> {
         LengthList(Stream::from_span(span))
     }
+
+    /// Collects all lengths into a `Vec`.
+    ///
+    /// Unlike a plain `collect()`, will stop on the first invalid length
+    /// and return its error, which contains the error position.
+    pub fn try_collect(self) -> StreamResult<Vec<Length>> {
+        self.collect()
+    }
 }
 
 impl<'a> fmt::Debug for LengthList<'a> {
@@ -71,14 +133,412 @@ impl<'a> fmt::Debug for LengthList<'a> {
     }
 }
 
+/// Writes lengths separated by a space.
+///
+/// Stops at the first invalid length.
+impl<'a> fmt::Display for LengthList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_list(*self, f)
+    }
+}
+
 impl<'a> Iterator for LengthList<'a> {
     type Item = StreamResult<Length>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Trailing spaces are not an error.
+        self.0.skip_spaces();
+
         if self.0.at_end() {
-            None
-        } else {
-            Some(self.0.parse_list_length())
+            return None;
         }
+
+        let v = self.0.parse_list_length();
+        if v.is_err() {
+            self.0.jump_to_end();
+        }
+
+        Some(v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(max_list_items(self.0)))
+    }
+}
+
+// The first item takes at least one byte and each next one
+// takes at least two bytes, since it should be preceded by a separator.
+fn max_list_items(mut s: Stream) -> usize {
+    match s.slice_tail().to_str().len() {
+        0 => 0,
+        len => (len - 1) / 2 + 1,
+    }
+}
+
+/// A shape reference used by the SVG 2 [`shape-inside`] and [`shape-subtract`] properties.
+///
+/// [`shape-inside`]: https://www.w3.org/TR/SVG2/text.html#TextShapeInside
+/// [`shape-subtract`]: https://www.w3.org/TR/SVG2/text.html#TextShapeSubtract
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ShapeRef<'a> {
+    /// A link to an element. Contains only `id` from `url(#id)`.
+    FuncIRI(&'a str),
+    /// A basic shape function as is. Like `circle(50%)`.
+    BasicShape(&'a str),
+}
+
+/// Iterator over a list of shape references.
+#[derive(Copy, Clone, PartialEq)]
+pub struct ShapeList<'a>(Stream<'a>);
+
+impl<'a> FromSpan<'a> for ShapeList<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        ShapeList(Stream::from_span(span))
+    }
+}
+
+impl<'a> ShapeList<'a> {
+    /// Collects all shape references into a `Vec`.
+    ///
+    /// Unlike a plain `collect()`, will stop on the first invalid reference
+    /// and return its error, which contains the error position.
+    pub fn try_collect(self) -> StreamResult<Vec<ShapeRef<'a>>> {
+        self.collect()
+    }
+}
+
+impl<'a> fmt::Debug for ShapeList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ShapeList({:?})", self.0.span())
     }
 }
+
+impl<'a> Iterator for ShapeList<'a> {
+    type Item = StreamResult<ShapeRef<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.skip_spaces();
+        if self.0.at_end() {
+            return None;
+        }
+
+        let r = parse_shape_ref(&mut self.0);
+        if r.is_err() {
+            self.0.jump_to_end();
+        }
+
+        Some(r)
+    }
+}
+
+fn parse_shape_ref<'a>(s: &mut Stream<'a>) -> StreamResult<ShapeRef<'a>> {
+    let start = s.pos();
+    let name = s.consume_name()?;
+
+    let shape = if name.to_str() == "url" {
+        s.consume_byte(b'(')?;
+        s.consume_byte(b'#')?;
+        let link = s.consume_name()?.to_str();
+        s.consume_byte(b')')?;
+        ShapeRef::FuncIRI(link)
+    } else {
+        // A basic shape can contain nested functions, like `calc()`.
+        s.consume_balanced_parens()?;
+        ShapeRef::BasicShape(s.slice_back(start).to_str())
+    };
+
+    s.skip_spaces();
+    if s.is_curr_byte_eq(b',') {
+        s.advance(1);
+    }
+
+    Ok(shape)
+}
+
+/// Iterator over the animation [`values`] list.
+///
+/// Splits the list by `;` and yields trimmed values as is,
+/// since their type depends on the animated attribute.
+/// Empty values are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::ValuesList;
+/// use svgparser::xmlparser::FromSpan;
+///
+/// let list: Vec<_> = ValuesList::from_str("0; 0.5; 1").map(|v| v.to_str()).collect();
+/// assert_eq!(list, vec!["0", "0.5", "1"]);
+/// ```
+///
+/// [`values`]: https://www.w3.org/TR/SVG/animate.html#ValuesAttribute
+#[derive(Copy, Clone, PartialEq)]
+pub struct ValuesList<'a>(Stream<'a>);
+
+impl<'a> FromSpan<'a> for ValuesList<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        ValuesList(Stream::from_span(span))
+    }
+}
+
+impl<'a> fmt::Debug for ValuesList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ValuesList({:?})", self.0.span())
+    }
+}
+
+impl<'a> Iterator for ValuesList<'a> {
+    type Item = StrSpan<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_list_item(&mut self.0, b';')
+    }
+}
+
+/// Iterator over a comma-separated list of strings.
+///
+/// Used by the [`systemLanguage`] attribute.
+/// Values are trimmed and empty values are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::CommaList;
+/// use svgparser::xmlparser::FromSpan;
+///
+/// assert_eq!(CommaList::from_str("en-US, fr").collect::<Vec<_>>(), vec!["en-US", "fr"]);
+/// ```
+///
+/// [`systemLanguage`]: https://www.w3.org/TR/SVG11/struct.html#SystemLanguageAttribute
+#[derive(Copy, Clone, PartialEq)]
+pub struct CommaList<'a>(Stream<'a>);
+
+impl<'a> FromSpan<'a> for CommaList<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        CommaList(Stream::from_span(span))
+    }
+}
+
+impl<'a> fmt::Debug for CommaList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CommaList({:?})", self.0.span())
+    }
+}
+
+impl<'a> Iterator for CommaList<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_list_item(&mut self.0, b',').map(|v| v.to_str())
+    }
+}
+
+// Returns the next trimmed, non-empty value of a list separated by `separator`.
+fn next_list_item<'a>(s: &mut Stream<'a>, separator: u8) -> Option<StrSpan<'a>> {
+    loop {
+        s.skip_spaces();
+        if s.at_end() {
+            return None;
+        }
+
+        let value = s.consume_bytes(|_, c| c != separator).trim();
+        if s.is_curr_byte_eq(separator) {
+            s.advance(1);
+        }
+
+        if !value.is_empty() {
+            return Some(value);
+        }
+    }
+}
+
+/// Iterator over a space-separated list of strings.
+///
+/// Used by the [`requiredFeatures`] and [`requiredExtensions`] attributes.
+///
+/// # Examples
+///
+/// ```
+/// use svgparser::SpaceList;
+/// use svgparser::xmlparser::FromSpan;
+///
+/// assert_eq!(SpaceList::from_str(" a  b ").collect::<Vec<_>>(), vec!["a", "b"]);
+/// ```
+///
+/// [`requiredFeatures`]: https://www.w3.org/TR/SVG11/struct.html#RequiredFeaturesAttribute
+/// [`requiredExtensions`]: https://www.w3.org/TR/SVG11/struct.html#RequiredExtensionsAttribute
+#[derive(Copy, Clone, PartialEq)]
+pub struct SpaceList<'a>(Stream<'a>);
+
+impl<'a> FromSpan<'a> for SpaceList<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        SpaceList(Stream::from_span(span))
+    }
+}
+
+impl<'a> fmt::Debug for SpaceList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SpaceList({:?})", self.0.span())
+    }
+}
+
+impl<'a> Iterator for SpaceList<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.skip_spaces();
+        if self.0.at_end() {
+            return None;
+        }
+
+        Some(self.0.consume_bytes(|_, c| !c.is_xml_space()).to_str())
+    }
+}
+
+/// Iterator over the animation [`keyTimes`] list.
+///
+/// Each value must be a number in the 0..1 range.
+///
+/// [`keyTimes`]: https://www.w3.org/TR/SVG/animate.html#KeyTimesAttribute
+#[derive(Copy, Clone, PartialEq)]
+pub struct KeyTimes<'a>(ValuesList<'a>);
+
+impl<'a> FromSpan<'a> for KeyTimes<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        KeyTimes(ValuesList::from_span(span))
+    }
+}
+
+impl<'a> KeyTimes<'a> {
+    /// Collects all values into a `Vec`.
+    ///
+    /// Unlike a plain `collect()`, will stop on the first invalid value
+    /// and return its error, which contains the error position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::KeyTimes;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// assert_eq!(KeyTimes::from_str("0; 0.5; 1").try_collect().unwrap(), vec![0.0, 0.5, 1.0]);
+    /// assert_eq!(KeyTimes::from_str("0; 2").try_collect().unwrap_err().to_string(),
+    ///            "invalid number at 1:4");
+    /// ```
+    pub fn try_collect(self) -> StreamResult<Vec<f64>> {
+        self.collect()
+    }
+}
+
+impl<'a> fmt::Debug for KeyTimes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KeyTimes({:?})", (self.0).0.span())
+    }
+}
+
+impl<'a> Iterator for KeyTimes<'a> {
+    type Item = StreamResult<f64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = try_opt!(self.0.next());
+        let mut s = Stream::from_span(span);
+
+        let r = s.parse_number().and_then(|n| {
+            if !s.at_end() || f64_bound(0.0, n, 1.0) != n {
+                return Err(StreamError::InvalidNumber(s.gen_error_pos_from(0)));
+            }
+
+            Ok(n)
+        });
+
+        if r.is_err() {
+            (self.0).0.jump_to_end();
+        }
+
+        Some(r)
+    }
+}
+
+/// Iterator over the animation [`keySplines`] list.
+///
+/// Each value is a group of four numbers: `x1 y1 x2 y2`.
+///
+/// [`keySplines`]: https://www.w3.org/TR/SVG/animate.html#KeySplinesAttribute
+#[derive(Copy, Clone, PartialEq)]
+pub struct KeySplines<'a>(ValuesList<'a>);
+
+impl<'a> FromSpan<'a> for KeySplines<'a> {
+    fn from_span(span: StrSpan<'a>) -> Self {
+        KeySplines(ValuesList::from_span(span))
+    }
+}
+
+impl<'a> KeySplines<'a> {
+    /// Collects all control points groups into a `Vec`.
+    ///
+    /// Unlike a plain `collect()`, will stop on the first invalid group
+    /// and return its error, which contains the error position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgparser::KeySplines;
+    /// use svgparser::xmlparser::FromSpan;
+    ///
+    /// let list = KeySplines::from_str("0 0 1 1; 0.5 0 0.5 1").try_collect().unwrap();
+    /// assert_eq!(list, vec![[0.0, 0.0, 1.0, 1.0], [0.5, 0.0, 0.5, 1.0]]);
+    /// ```
+    pub fn try_collect(self) -> StreamResult<Vec<[f64; 4]>> {
+        self.collect()
+    }
+}
+
+impl<'a> fmt::Debug for KeySplines<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KeySplines({:?})", (self.0).0.span())
+    }
+}
+
+impl<'a> Iterator for KeySplines<'a> {
+    type Item = StreamResult<[f64; 4]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = try_opt!(self.0.next());
+        let mut s = Stream::from_span(span);
+
+        let mut group = [0.0; 4];
+        let r = s.parse_numbers_into(&mut group).and_then(|count| {
+            if count == 4 && s.at_end() {
+                Ok(group)
+            } else {
+                Err(StreamError::InvalidKeySpline(s.gen_error_pos_from(0)))
+            }
+        });
+
+        if r.is_err() {
+            (self.0).0.jump_to_end();
+        }
+
+        Some(r)
+    }
+}
+
+fn write_list<T: fmt::Display, I: Iterator<Item = StreamResult<T>>>(
+    list: I,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    for (i, item) in list.enumerate() {
+        let item = match item {
+            Ok(v) => v,
+            Err(_) => break,
+        };
+
+        if i != 0 {
+            write!(f, " ")?;
+        }
+
+        write!(f, "{}", item)?;
+    }
+
+    Ok(())
+}
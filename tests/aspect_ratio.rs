@@ -46,3 +46,27 @@ test!(slice_2, "xMinYMid meet", AspectRatio {
     align: Align::XMinYMid,
     slice: false,
 });
+
+test!(defer_3, "defer xMidYMid meet", AspectRatio {
+    defer: true,
+    align: Align::XMidYMid,
+    slice: false,
+});
+
+test!(defer_4, "  defer   xMaxYMax   slice  ", AspectRatio {
+    defer: true,
+    align: Align::XMaxYMax,
+    slice: true,
+});
+
+test!(slice_3, "xMinYMin slice", AspectRatio {
+    defer: false,
+    align: Align::XMinYMin,
+    slice: true,
+});
+
+#[test]
+fn defer_err_1() {
+    assert_eq!(AspectRatio::from_str("defer").unwrap_err().to_string(),
+               "unexpected end of stream");
+}
@@ -1,15 +1,34 @@
 extern crate svgparser;
 
 use svgparser::{
+    parse_fe_color_matrix_values,
+    path,
+    xmlparser,
     AttributeId as AId,
     AttributeValue as AV,
+    ClipRect,
+    ClockValue,
     Color,
+    ColorMatrix,
+    CssWideKeyword,
+    FontSize,
     ElementId,
+    FilterInput,
+    FuncIRI,
+    Length,
+    LengthUnit,
+    Orient,
     PaintFallback,
+    ShapeRef,
     ValueId,
     ViewBox,
 };
 
+use xmlparser::{
+    StrSpan,
+    XmlSpace,
+};
+
 macro_rules! test {
     ($name:ident, $aid:expr, $text:expr, $result:expr) => (
         #[test]
@@ -25,7 +44,7 @@ macro_rules! test_err {
         #[test]
         fn $name() {
             let v = AV::from_str(ElementId::Rect, "", $aid, $text);
-            assert_eq!(v.unwrap_err().to_string(), $err);
+            assert_eq!(v.unwrap_err().source.to_string(), $err);
         }
     )
 }
@@ -44,22 +63,108 @@ test!(paint_3, AId::Fill, "inherit", AV::PredefValue(ValueId::Inherit));
 
 test!(paint_4, AId::Fill, "red", AV::Color(Color::new(255, 0, 0)));
 
-test!(paint_5, AId::Fill, "url(#link)", AV::FuncIRI("link"));
+test!(paint_5, AId::Fill, "url(#link)", AV::FuncIRI(FuncIRI::local("link")));
 
 test!(paint_6, AId::Fill, "url(#link) red",
-    AV::FuncIRIWithFallback("link", PaintFallback::Color(Color::new(255, 0, 0))));
+    AV::FuncIRIWithFallback(FuncIRI::local("link"), PaintFallback::Color(Color::new(255, 0, 0))));
 
 // same as above, but for `stroke`
 test!(paint_7, AId::Stroke, "url(#link) red",
-    AV::FuncIRIWithFallback("link", PaintFallback::Color(Color::new(255, 0, 0))));
+    AV::FuncIRIWithFallback(FuncIRI::local("link"), PaintFallback::Color(Color::new(255, 0, 0))));
 
 test!(paint_8, AId::Fill, "url(#link) none",
-    AV::FuncIRIWithFallback("link", PaintFallback::PredefValue(ValueId::None)));
+    AV::FuncIRIWithFallback(FuncIRI::local("link"), PaintFallback::PredefValue(ValueId::None)));
 
 test!(ref_1, AId::Class, "&ref;", AV::EntityRef("ref"));
+test!(ref_2, AId::Fill, "&a;", AV::EntityRef("a"));
+test!(ref_3, AId::Fill, " &a; ", AV::EntityRef("a"));
+test!(ref_4, AId::Fill, "&a;&b;", AV::String("&a;&b;"));
+test!(ref_5, AId::Fill, "url(#x)&a;", AV::String("url(#x)&a;"));
+test!(ref_6, AId::Fill, "red &suffix;", AV::String("red &suffix;"));
+// character references are not entities
+test!(ref_7, AId::Class, "a&#x20;b", AV::String("a&#x20;b"));
+
+test!(kerning_1, AId::Kerning, "-2px", AV::Length(Length::new(-2.0, LengthUnit::Px)));
+test!(kerning_2, AId::Kerning, "0", AV::Length(Length::new(0.0, LengthUnit::None)));
+test!(kerning_3, AId::Kerning, "auto", AV::PredefValue(ValueId::Auto));
+test!(kerning_4, AId::Kerning, "inherit", AV::PredefValue(ValueId::Inherit));
+
+test!(word_spacing_1, AId::WordSpacing, "-2px", AV::Length(Length::new(-2.0, LengthUnit::Px)));
+test!(word_spacing_2, AId::WordSpacing, "0", AV::Length(Length::new(0.0, LengthUnit::None)));
+test!(word_spacing_3, AId::WordSpacing, "normal", AV::PredefValue(ValueId::Normal));
+test!(word_spacing_4, AId::WordSpacing, "inherit", AV::PredefValue(ValueId::Inherit));
+
+test!(letter_spacing_1, AId::LetterSpacing, "-2px", AV::Length(Length::new(-2.0, LengthUnit::Px)));
+test!(letter_spacing_2, AId::LetterSpacing, "0", AV::Length(Length::new(0.0, LengthUnit::None)));
+test!(letter_spacing_3, AId::LetterSpacing, "normal", AV::PredefValue(ValueId::Normal));
+test!(letter_spacing_4, AId::LetterSpacing, "inherit", AV::PredefValue(ValueId::Inherit));
+
+test!(dashoffset_1, AId::StrokeDashoffset, "-5", AV::Length(Length::new(-5.0, LengthUnit::None)));
+test!(dashoffset_2, AId::StrokeDashoffset, "25%", AV::Length(Length::new(25.0, LengthUnit::Percent)));
+test!(dashoffset_3, AId::StrokeDashoffset, "inherit", AV::PredefValue(ValueId::Inherit));
+test!(dashoffset_4, AId::StrokeDashoffset, "-1.5em", AV::Length(Length::new(-1.5, LengthUnit::Em)));
+
+test!(num_octaves_1, AId::NumOctaves, "3", AV::Number(3.0));
+test!(num_octaves_2, AId::NumOctaves, "0", AV::Number(0.0));
+test_err!(num_octaves_err_1, AId::NumOctaves, "-1", "invalid number at 1:1");
+test_err!(num_octaves_err_2, AId::NumOctaves, "1.5", "invalid number at 1:1");
+
+test!(repeat_count_1, AId::RepeatCount, "indefinite", AV::PredefValue(ValueId::Indefinite));
+test!(repeat_count_2, AId::RepeatCount, "2.5", AV::Number(2.5));
+test_err!(repeat_count_err_1, AId::RepeatCount, "0", "invalid number at 1:1");
+test_err!(repeat_count_err_2, AId::RepeatCount, "auto", "invalid number at 1:1");
+
+#[test]
+fn system_language_1() {
+    match AV::from_str(ElementId::Switch, "", AId::SystemLanguage, "en, fr").unwrap() {
+        AV::CommaList(list) => assert_eq!(list.collect::<Vec<_>>(), vec!["en", "fr"]),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn required_features_1() {
+    match AV::from_str(ElementId::Switch, "", AId::RequiredFeatures, "a b").unwrap() {
+        AV::SpaceList(list) => assert_eq!(list.collect::<Vec<_>>(), vec!["a", "b"]),
+        _ => unreachable!(),
+    }
+}
+
+test!(filter_input_1, AId::In, "SourceGraphic", AV::FilterInput(FilterInput::SourceGraphic));
+test!(filter_input_2, AId::In2, "BackgroundAlpha", AV::FilterInput(FilterInput::BackgroundAlpha));
+test!(filter_input_3, AId::In, " myResult ", AV::FilterInput(FilterInput::Reference("myResult")));
+test!(filter_input_4, AId::In, "sourceGraphic", AV::FilterInput(FilterInput::Reference("sourceGraphic")));
+test_err!(filter_input_err_1, AId::In2, " ", "unexpected end of stream");
+
+test!(dur_1, AId::Dur, "2s", AV::ClockValue(ClockValue::new(2.0)));
+test!(dur_2, AId::Dur, "1.5min", AV::ClockValue(ClockValue::new(90.0)));
+test!(dur_3, AId::Dur, "00:01:30", AV::ClockValue(ClockValue::new(90.0)));
+test!(dur_4, AId::Dur, "media", AV::PredefValue(ValueId::Media));
+test_err!(dur_err_1, AId::Dur, "auto", "invalid clock value at 1:1");
+
+test!(repeat_dur_1, AId::RepeatDur, "indefinite", AV::PredefValue(ValueId::Indefinite));
+test!(repeat_dur_2, AId::RepeatDur, "300ms", AV::ClockValue(ClockValue::new(0.3)));
+test_err!(repeat_dur_err_1, AId::RepeatDur, "media", "invalid clock value at 1:1");
 
 test!(eb_1, AId::EnableBackground, "new    ", AV::String("new"));
 
+macro_rules! test_eid {
+    ($name:ident, $eid:expr, $aid:expr, $text:expr, $result:expr) => (
+        #[test]
+        fn $name() {
+            let v = AV::from_str($eid, "", $aid, $text).unwrap();
+            assert_eq!(v, $result);
+        }
+    )
+}
+
+test_eid!(stop_offset_1, ElementId::Stop, AId::Offset, "50%", AV::Number(0.5));
+test_eid!(stop_offset_2, ElementId::Stop, AId::Offset, "1.5", AV::Number(1.0));
+test_eid!(stop_offset_3, ElementId::Stop, AId::Offset, "-1", AV::Number(0.0));
+test_eid!(stop_offset_4, ElementId::Stop, AId::Offset, "0.3", AV::Number(0.3));
+test_eid!(offset_1, ElementId::FeFuncR, AId::Offset, "1.5",
+          AV::Length(Length::new(1.5, LengthUnit::None)));
+
 test!(vb_1, AId::ViewBox, "10 20 30 40",
     AV::ViewBox(ViewBox { x: 10.0, y: 20.0, w: 30.0, h: 40.0 }));
 
@@ -75,6 +180,20 @@ test!(vb_4, AId::ViewBox, "-10 -20 30 40",
 // color is last type that we check during parsing <paint>, so any error will be like that
 test_err!(paint_err_1, AId::Fill, "#link", "invalid color at 1:1");
 test_err!(paint_err_2, AId::Fill, "text", "invalid color at 1:1");
+test_err!(paint_err_3, AId::Fill, "url(#)", "invalid FuncIRI at 1:6");
+test_err!(paint_err_4, AId::Stroke, "url(#link", "invalid FuncIRI at 1:10");
+
+test_err!(func_iri_err_1, AId::Mask, "url(#)", "invalid FuncIRI at 1:6");
+test_err!(func_iri_err_2, AId::Mask, "url(#id", "invalid FuncIRI at 1:8");
+test_err!(func_iri_err_3, AId::Mask, "rgb(id)", "expected a FuncIRI not 'rgb(id)'");
+
+test!(func_iri_1, AId::Mask, "url(#id)", AV::FuncIRI(FuncIRI::new(None, Some("id"))));
+test!(func_iri_2, AId::Mask, "url(other.svg#id)",
+    AV::FuncIRI(FuncIRI::new(Some("other.svg"), Some("id"))));
+test!(func_iri_3, AId::Mask, "url(sprite)", AV::FuncIRI(FuncIRI::new(Some("sprite"), None)));
+test!(func_iri_4, AId::Fill, "url(other.svg#id) red",
+    AV::FuncIRIWithFallback(FuncIRI::new(Some("other.svg"), Some("id")),
+                            PaintFallback::Color(Color::new(255, 0, 0))));
 
 test_err!(vb_err_1, AId::ViewBox, "qwe", "invalid number at 1:1");
 test_err!(vb_err_2, AId::ViewBox, "10 20 30 0", "viewBox should have a positive size");
@@ -85,3 +204,366 @@ test_err!(vb_err_6, AId::ViewBox, "10 20 30 -40", "viewBox should have a positiv
 test_err!(vb_err_7, AId::ViewBox, "10 20 -30 -40", "viewBox should have a positive size");
 
 // TODO: test all supported attributes, probably via codegen.
+
+test!(vb_5, AId::ViewBox, "0,0,100,100",
+    AV::ViewBox(ViewBox { x: 0.0, y: 0.0, w: 100.0, h: 100.0 }));
+
+test!(vb_6, AId::ViewBox, "-10 -10 20 20",
+    AV::ViewBox(ViewBox { x: -10.0, y: -10.0, w: 20.0, h: 20.0 }));
+
+test!(vb_7, AId::ViewBox, "-10,-10,20,20",
+    AV::ViewBox(ViewBox { x: -10.0, y: -10.0, w: 20.0, h: 20.0 }));
+
+test!(vb_8, AId::ViewBox, "0 , 0 ,100 ,100",
+    AV::ViewBox(ViewBox { x: 0.0, y: 0.0, w: 100.0, h: 100.0 }));
+
+test!(vb_9, AId::ViewBox, "-10-10 20 20",
+    AV::ViewBox(ViewBox { x: -10.0, y: -10.0, w: 20.0, h: 20.0 }));
+
+test!(vb_10, AId::ViewBox, "+0 +0 +1e2 +1e2",
+    AV::ViewBox(ViewBox { x: 0.0, y: 0.0, w: 100.0, h: 100.0 }));
+
+test_err!(vb_err_8, AId::ViewBox, "10 20 30", "viewBox should contain four numbers");
+test_err!(vb_err_9, AId::ViewBox, "10,20,30,", "viewBox should contain four numbers");
+test_err!(vb_err_10, AId::ViewBox, "-10,-10,-20,20", "viewBox should have a positive size");
+
+test!(opacity_1, AId::FillOpacity, "0.5", AV::Number(0.5));
+test!(opacity_2, AId::FillOpacity, "50%", AV::Number(0.5));
+test!(opacity_3, AId::FillOpacity, "150%", AV::Number(1.0));
+test!(opacity_4, AId::Opacity, "-20%", AV::Number(0.0));
+test!(opacity_5, AId::StopOpacity, "inherit", AV::PredefValue(ValueId::Inherit));
+
+test!(baseline_shift_1, AId::BaselineShift, "sub", AV::PredefValue(ValueId::Sub));
+test!(baseline_shift_2, AId::BaselineShift, "super", AV::PredefValue(ValueId::Super));
+test!(baseline_shift_3, AId::BaselineShift, "-50%",
+    AV::Length(Length::new(-50.0, LengthUnit::Percent)));
+test!(baseline_shift_4, AId::BaselineShift, "20%",
+    AV::Length(Length::new(20.0, LengthUnit::Percent)));
+test!(baseline_shift_5, AId::BaselineShift, "3px", AV::Length(Length::new(3.0, LengthUnit::Px)));
+test!(baseline_shift_6, AId::BaselineShift, "+3", AV::Length(Length::new(3.0, LengthUnit::None)));
+test_err!(baseline_shift_err_1, AId::BaselineShift, "bottom", "invalid number at 1:1");
+
+test!(css_wide_1, AId::Fill, "initial", AV::CssWide(CssWideKeyword::Initial));
+test!(css_wide_2, AId::Fill, "unset", AV::CssWide(CssWideKeyword::Unset));
+test!(css_wide_3, AId::Fill, "revert", AV::CssWide(CssWideKeyword::Revert));
+test!(css_wide_4, AId::Fill, "inherit", AV::PredefValue(ValueId::Inherit));
+test!(css_wide_5, AId::StrokeWidth, " unset ", AV::CssWide(CssWideKeyword::Unset));
+test!(css_wide_6, AId::Display, "initial", AV::CssWide(CssWideKeyword::Initial));
+// not a presentation attribute
+test!(css_wide_7, AId::Class, "initial", AV::String("initial"));
+// keywords can't be combined with other values
+test_err!(css_wide_err_1, AId::Fill, "initial red", "invalid color at 1:1");
+
+#[test]
+fn css_wide_animation_fill() {
+    let v = AV::from_str(ElementId::Animate, "", AId::Fill, "initial").unwrap();
+    assert_eq!(v, AV::String("initial"));
+}
+
+test!(number_opt_number_1, AId::StdDeviation, "2", AV::NumberOptNumber(2.0, 2.0));
+test!(number_opt_number_2, AId::StdDeviation, "2 3", AV::NumberOptNumber(2.0, 3.0));
+test!(number_opt_number_3, AId::BaseFrequency, " 0.05, 0.1 ", AV::NumberOptNumber(0.05, 0.1));
+test_err!(number_opt_number_err_1, AId::StdDeviation, "2 3 4", "invalid number at 1:5");
+test_err!(number_opt_number_err_2, AId::BaseFrequency, "", "unexpected end of stream");
+test_err!(number_opt_number_err_3, AId::StdDeviation, "1,", "invalid number at 1:3");
+test_err!(number_opt_number_err_4, AId::StdDeviation, "1 2,", "invalid number at 1:4");
+
+test!(path_none, AId::D, "none", AV::PredefValue(ValueId::None));
+test_err!(path_func_err_1, AId::D, "path('M0 0'", "unexpected end of stream");
+test_err!(path_func_err_2, AId::D, "path('M0 0') x", "expected 'end of stream' not 'x' at 1:14");
+
+#[test]
+fn path_func_1() {
+    match AV::from_str(ElementId::Path, "", AId::D, "path( 'M 10 20' )").unwrap() {
+        AV::Path(mut p) => {
+            assert_eq!(p.next(), Some(path::Token::MoveTo { abs: true, x: 10.0, y: 20.0 }));
+            assert_eq!(p.next(), None);
+        }
+        _ => unreachable!(),
+    }
+}
+
+test!(clip_1, AId::Clip, "auto", AV::PredefValue(ValueId::Auto));
+test!(clip_2, AId::Clip, "inherit", AV::PredefValue(ValueId::Inherit));
+test!(clip_3, AId::Clip, "rect(0 auto 10 0)", AV::ClipRect(ClipRect::new(
+    Some(Length::new(0.0, LengthUnit::None)),
+    None,
+    Some(Length::new(10.0, LengthUnit::None)),
+    Some(Length::new(0.0, LengthUnit::None)),
+)));
+test!(clip_4, AId::Clip, "rect(5px, auto, auto, 1mm)", AV::ClipRect(ClipRect::new(
+    Some(Length::new(5.0, LengthUnit::Px)),
+    None,
+    None,
+    Some(Length::new(1.0, LengthUnit::Mm)),
+)));
+test_err!(clip_err_1, AId::Clip, "rect(0 0 0)", "invalid number at 1:11");
+test_err!(clip_err_2, AId::Clip, "rect(autofoo 0 0 0)", "invalid number at 1:6");
+test_err!(clip_err_3, AId::Clip, "rect(0 auto-x 0 0)", "invalid number at 1:8");
+test_err!(clip_err_4, AId::Clip, "rect(0 auto 10 0) x", "expected 'end of stream' not 'x' at 1:19");
+
+test!(shape_inside_1, AId::ShapeInside, "none", AV::PredefValue(ValueId::None));
+test!(shape_inside_2, AId::ShapeInside, "auto", AV::PredefValue(ValueId::Auto));
+test!(shape_subtract_1, AId::ShapeSubtract, "none", AV::PredefValue(ValueId::None));
+
+#[test]
+fn shape_inside_3() {
+    match AV::from_str(ElementId::Text, "", AId::ShapeInside, "url(#s)").unwrap() {
+        AV::ShapeList(list) => {
+            assert_eq!(list.try_collect().unwrap(), vec![ShapeRef::FuncIRI("s")]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn values_list_1() {
+    match AV::from_str(ElementId::Animate, "", AId::Values, "0; 0.5; 1").unwrap() {
+        AV::ValuesList(list) => {
+            let list: Vec<_> = list.map(|v| v.to_str()).collect();
+            assert_eq!(list, vec!["0", "0.5", "1"]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn key_times_1() {
+    match AV::from_str(ElementId::Animate, "", AId::KeyTimes, "0;0.5;1").unwrap() {
+        AV::KeyTimes(list) => assert_eq!(list.try_collect().unwrap(), vec![0.0, 0.5, 1.0]),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn key_splines_1() {
+    match AV::from_str(ElementId::Animate, "", AId::KeySplines, "0 0 1 1;0.5 0 0.5 1").unwrap() {
+        AV::KeySplines(list) => assert_eq!(list.count(), 2),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn font_1() {
+    match AV::from_str(ElementId::Text, "", AId::Font, "italic bold 12px/1.5 Arial").unwrap() {
+        AV::Font(font) => {
+            assert_eq!(font.style, Some(ValueId::Italic));
+            assert_eq!(font.variant, None);
+            assert_eq!(font.weight, Some(ValueId::Bold));
+            assert_eq!(font.size, FontSize::Length(Length::new(12.0, LengthUnit::Px)));
+            assert_eq!(font.line_height, Some(Length::new(1.5, LengthUnit::None)));
+            assert_eq!(font.family, "Arial");
+        }
+        _ => unreachable!(),
+    }
+}
+
+// family only is not a valid shorthand
+test!(font_2, AId::Font, "Verdana", AV::String("Verdana"));
+test!(font_3, AId::Font, "inherit", AV::PredefValue(ValueId::Inherit));
+
+#[test]
+fn accessors_1() {
+    let v = AV::Length(Length::new(1.0, LengthUnit::Px));
+    assert_eq!(v.as_length(), Some(Length::new(1.0, LengthUnit::Px)));
+    assert_eq!(v.as_color(), None);
+    assert_eq!(v.as_number(), None);
+}
+
+#[test]
+fn prefix_1() {
+    let v = AV::from_str(ElementId::Rect, "custom", AId::X, "  1  2 ").unwrap();
+    assert_eq!(v, AV::String("  1  2 "));
+}
+
+#[test]
+fn strict_1() {
+    let span = StrSpan::from_str("text");
+    assert_eq!(AV::from_span(ElementId::Rect, "", AId::Class, span).unwrap(),
+               AV::String("text"));
+
+    let v = AV::from_span_strict(ElementId::Rect, "", AId::Class, span);
+    assert_eq!(v.unwrap_err().source.to_string(), "the 'class' attribute value can't be parsed");
+}
+
+#[test]
+fn attribute_error_1() {
+    let e = AV::from_str(ElementId::Rect, "", AId::Width, "abc").unwrap_err();
+    assert_eq!(e.eid, ElementId::Rect);
+    assert_eq!(e.aid, AId::Width);
+    assert_eq!(e.to_string(),
+               "failed to parse the 'width' attribute of the 'rect' element: invalid number at 1:1");
+}
+
+#[test]
+fn strict_2() {
+    let span = StrSpan::from_str("10");
+    assert_eq!(AV::from_span_strict(ElementId::Rect, "", AId::Width, span).unwrap(),
+               AV::Length(Length::new(10.0, LengthUnit::None)));
+}
+
+#[test]
+fn accessors_2() {
+    assert_eq!(AV::Number(0.5).as_number(), Some(0.5));
+    assert_eq!(AV::Color(Color::new(1, 2, 3)).as_color(), Some(Color::new(1, 2, 3)));
+    assert_eq!(AV::IRI("id").as_iri(), Some("id"));
+    assert_eq!(AV::FuncIRI(FuncIRI::local("id")).as_iri(), None);
+    assert_eq!(AV::FuncIRI(FuncIRI::local("id")).as_func_iri(), Some("id"));
+    assert_eq!(AV::FuncIRI(FuncIRI::new(Some("sprite"), None)).as_func_iri(), Some("sprite"));
+    assert_eq!(AV::PredefValue(ValueId::None).as_predef(), Some(ValueId::None));
+    assert_eq!(AV::String("none").as_predef(), None);
+}
+
+#[test]
+fn string_span_1() {
+    let text = "<text class=' big red '/>";
+    let span = StrSpan::from_substr(text, 13, 22);
+    let v = AV::from_span(ElementId::Text, "", AId::Class, span).unwrap();
+    assert_eq!(v, AV::String("big red"));
+
+    let s = v.as_string_span(span).unwrap();
+    assert_eq!(s.start(), 14);
+    assert_eq!(s.to_str(), "big red");
+}
+
+#[test]
+fn string_span_2() {
+    // Values of prefixed attributes are not trimmed.
+    let text = "<text my:class=' big red '/>";
+    let span = StrSpan::from_substr(text, 16, 25);
+    let v = AV::from_span(ElementId::Text, "my", AId::Class, span).unwrap();
+    assert_eq!(v, AV::String(" big red "));
+    assert_eq!(v.as_string_span(span).unwrap().start(), 16);
+
+    assert_eq!(AV::Number(1.0).as_string_span(span), None);
+}
+
+#[test]
+fn vb_from_str() {
+    assert_eq!("0 0 10 10".parse::<ViewBox>().unwrap(), ViewBox::new(0.0, 0.0, 10.0, 10.0));
+    assert_eq!("0 0 10".parse::<ViewBox>().unwrap_err().to_string(),
+               "viewBox should contain four numbers");
+    assert_eq!("0 0 10 0".parse::<ViewBox>().unwrap_err().to_string(),
+               "viewBox should have a positive size");
+}
+
+#[test]
+fn vb_approx_eq() {
+    let vb1 = ViewBox::new(0.0, 0.0, 10.0, 10.0);
+    let vb2 = ViewBox::new(1e-12, 0.0, 10.0, 10.0 - 1e-12);
+    assert_ne!(vb1, vb2);
+    assert!(vb1.approx_eq(&vb2, 1e-10));
+    assert!(!vb1.approx_eq(&vb2, 1e-13));
+}
+
+#[test]
+fn color_matrix_1() {
+    let text = "1 0 0 0 0  0 1 0 0 0  0 0 1 0 0  0 0 0 1 0";
+    let m = parse_fe_color_matrix_values("matrix", StrSpan::from_str(text)).unwrap();
+    assert_eq!(m, ColorMatrix::Matrix([
+        1.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 1.0, 0.0,
+    ]));
+}
+
+#[test]
+fn color_matrix_2() {
+    let m = parse_fe_color_matrix_values("saturate", StrSpan::from_str(" 0.2 ")).unwrap();
+    assert_eq!(m, ColorMatrix::Saturate(0.2));
+}
+
+#[test]
+fn color_matrix_3() {
+    let m = parse_fe_color_matrix_values("luminanceToAlpha", StrSpan::from_str("1 2 3")).unwrap();
+    assert_eq!(m, ColorMatrix::LuminanceToAlpha);
+}
+
+#[test]
+fn color_matrix_err_1() {
+    let e = parse_fe_color_matrix_values("matrix", StrSpan::from_str("1 0 0 0 0")).unwrap_err();
+    assert_eq!(e.to_string(), "color matrix values should contain 20 number(s), not 5");
+}
+
+#[test]
+fn color_matrix_err_2() {
+    let e = parse_fe_color_matrix_values("hueRotate", StrSpan::from_str("")).unwrap_err();
+    assert_eq!(e.to_string(), "color matrix values should contain 1 number(s), not 0");
+}
+
+#[test]
+fn color_matrix_err_3() {
+    let e = parse_fe_color_matrix_values("scale", StrSpan::from_str("1")).unwrap_err();
+    assert_eq!(e.to_string(), "current attribute doesn't support the 'scale' value");
+}
+
+#[test]
+fn marker_shorthand_1() {
+    let v = AV::from_str(ElementId::Path, "", AId::Marker, "url(#m)").unwrap();
+    let link = AV::FuncIRI(FuncIRI::local("m"));
+    assert_eq!(v.expand_marker_shorthand(), [
+        (AId::MarkerStart, link),
+        (AId::MarkerMid, link),
+        (AId::MarkerEnd, link),
+    ]);
+}
+
+#[test]
+fn marker_shorthand_2() {
+    let v = AV::from_str(ElementId::Path, "", AId::Marker, "none").unwrap();
+    for &(_, v) in &v.expand_marker_shorthand() {
+        assert_eq!(v, AV::PredefValue(ValueId::None));
+    }
+}
+
+#[test]
+fn xml_space_1() {
+    let v = AV::from_str(ElementId::Text, "xml", AId::Space, "default").unwrap();
+    assert_eq!(v, AV::XmlSpace(XmlSpace::Default));
+}
+
+#[test]
+fn xml_space_2() {
+    let v = AV::from_str(ElementId::Text, "xml", AId::Space, " preserve ").unwrap();
+    assert_eq!(v, AV::XmlSpace(XmlSpace::Preserve));
+}
+
+#[test]
+fn xml_space_err_1() {
+    let v = AV::from_str(ElementId::Text, "xml", AId::Space, "keep");
+    assert_eq!(v.unwrap_err().source.to_string(),
+               "current attribute doesn't support the 'keep' value");
+}
+
+test!(orient_1, AId::Orient, "auto", AV::Orient(Orient::Auto));
+test!(orient_2, AId::Orient, "auto-start-reverse", AV::Orient(Orient::AutoStartReverse));
+test!(orient_3, AId::Orient, "45", AV::Orient(Orient::Angle(45.0)));
+test!(orient_4, AId::Orient, "90deg", AV::Orient(Orient::Angle(90.0)));
+test!(orient_5, AId::Orient, "100grad", AV::Orient(Orient::Angle(90.0)));
+test!(orient_6, AId::Orient, "-0.5rad", AV::Orient(Orient::Angle(-0.5f64.to_degrees())));
+
+test_err!(orient_err_1, AId::Orient, "90px", "invalid angle at 1:3");
+test_err!(orient_err_2, AId::Orient, "auto 45", "invalid number at 1:1");
+
+#[test]
+fn animate_motion_path_1() {
+    let v = AV::from_str(ElementId::AnimateMotion, "", AId::Path, "M0 0 L10 10").unwrap();
+    match v {
+        AV::Path(p) => {
+            let tokens: Vec<_> = p.collect();
+            assert_eq!(tokens, vec![
+                path::Token::MoveTo { abs: true, x: 0.0, y: 0.0 },
+                path::Token::LineTo { abs: true, x: 10.0, y: 10.0 },
+            ]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn animate_motion_path_2() {
+    // 'path' is a path data only on 'animateMotion'.
+    let v = AV::from_str(ElementId::Rect, "", AId::Path, "M0 0 L10 10").unwrap();
+    assert_eq!(v, AV::String("M0 0 L10 10"));
+}
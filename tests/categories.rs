@@ -0,0 +1,98 @@
+extern crate svgparser;
+
+use svgparser::{
+    AttributeId as AId,
+    ElementId as EId,
+    ValueId,
+};
+
+#[test]
+fn element_shape() {
+    assert!(EId::Rect.is_shape());
+    assert!(EId::Path.is_shape());
+    assert!(!EId::Text.is_shape());
+}
+
+#[test]
+fn element_graphic() {
+    assert!(EId::Rect.is_graphic());
+    assert!(EId::Use.is_graphic());
+    assert!(!EId::G.is_graphic());
+}
+
+#[test]
+fn element_gradient() {
+    assert!(EId::LinearGradient.is_gradient());
+    assert!(EId::RadialGradient.is_gradient());
+    assert!(!EId::Pattern.is_gradient());
+}
+
+#[test]
+fn element_filter_primitive() {
+    assert!(EId::FeBlend.is_filter_primitive());
+    assert!(EId::FeTurbulence.is_filter_primitive());
+    assert!(!EId::FeFuncA.is_filter_primitive());
+    assert!(!EId::Filter.is_filter_primitive());
+}
+
+#[test]
+fn element_text_content() {
+    assert!(EId::Tspan.is_text_content());
+    assert!(EId::TextPath.is_text_content());
+    assert!(!EId::Title.is_text_content());
+}
+
+#[test]
+fn element_container() {
+    assert!(EId::G.is_container());
+    assert!(EId::Svg.is_container());
+    assert!(!EId::Rect.is_container());
+}
+
+#[test]
+fn attribute_presentation() {
+    assert!(AId::Fill.is_presentation());
+    assert!(AId::StrokeWidth.is_presentation());
+    assert!(!AId::X.is_presentation());
+    assert!(!AId::Transform.is_presentation());
+}
+
+#[test]
+fn attribute_event() {
+    assert!(AId::Onclick.is_event());
+    assert!(AId::Onzoom.is_event());
+    assert!(!AId::Fill.is_event());
+}
+
+#[test]
+fn attribute_inheritable() {
+    assert!(AId::Fill.is_inheritable());
+    assert!(AId::FontSize.is_inheritable());
+    assert!(!AId::Opacity.is_inheritable());
+    assert!(!AId::X.is_inheritable());
+}
+
+#[test]
+fn value_global() {
+    assert!(ValueId::Inherit.is_global());
+    assert!(!ValueId::None.is_global());
+}
+
+#[test]
+fn value_paint_keyword() {
+    assert!(ValueId::None.is_paint_keyword());
+    assert!(ValueId::CurrentColor.is_paint_keyword());
+    assert!(!ValueId::Inherit.is_paint_keyword());
+}
+
+#[test]
+fn value_color_keyword() {
+    assert!(ValueId::CurrentColor.is_color_keyword());
+    assert!(!ValueId::None.is_color_keyword());
+}
+
+#[test]
+fn value_auto() {
+    assert!(ValueId::Auto.is_auto());
+    assert!(!ValueId::Normal.is_auto());
+}
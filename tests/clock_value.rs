@@ -0,0 +1,47 @@
+extern crate svgparser;
+
+use std::str::FromStr;
+
+use svgparser::{
+    ClockValue,
+};
+
+macro_rules! test {
+    ($name:ident, $text:expr, $result:expr) => (
+        #[test]
+        fn $name() {
+            assert_eq!(ClockValue::from_str($text).unwrap(), ClockValue::new($result));
+        }
+    )
+}
+
+test!(timecount_1, "2s", 2.0);
+test!(timecount_2, "2", 2.0);
+test!(timecount_3, "300ms", 0.3);
+test!(timecount_4, "1.5min", 90.0);
+test!(timecount_5, "1h", 3600.0);
+test!(timecount_6, " 0.5s ", 0.5);
+test!(partial_clock_1, "02:30", 150.0);
+test!(partial_clock_2, "00:10.25", 10.25);
+test!(full_clock_1, "00:01:30", 90.0);
+test!(full_clock_2, "50:00:10.5", 180010.5);
+
+macro_rules! test_err {
+    ($name:ident, $text:expr, $err:expr) => (
+        #[test]
+        fn $name() {
+            assert_eq!(ClockValue::from_str($text).unwrap_err().to_string(), $err);
+        }
+    )
+}
+
+test_err!(err_1, "", "invalid clock value at 1:1");
+test_err!(err_2, "2sec", "invalid clock value at 1:1");
+test_err!(err_3, "-1s", "invalid clock value at 1:1");
+test_err!(err_4, "1e2s", "invalid clock value at 1:1");
+test_err!(err_5, ".5s", "invalid clock value at 1:1");
+test_err!(err_6, "1:30", "invalid clock value at 1:1");
+test_err!(err_7, "00:60", "invalid clock value at 1:1");
+test_err!(err_8, "00:00:60", "invalid clock value at 1:1");
+test_err!(err_9, "1:00:00:00", "invalid clock value at 1:1");
+test_err!(err_10, " 2s 3s", "invalid clock value at 1:2");
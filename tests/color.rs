@@ -55,7 +55,7 @@ test_parse!(
 test_parse!(
     rgb_percentage,
     "rgb(50%, 50%, 50%)",
-    Color::new(127, 127, 127)
+    Color::new(128, 128, 128)
 );
 
 test_parse!(
@@ -139,6 +139,102 @@ test_error!(
     "invalid color at 1:5"
 );
 
+test_parse!(
+    rgb_numeric_clamp,
+    "rgb(300,0,0)",
+    Color::new(255, 0, 0)
+);
+
+test_parse!(
+    rgb_numeric_fract_1,
+    "rgb(127.5, 0, 0)",
+    Color::new(128, 0, 0)
+);
+
+test_parse!(
+    rgb_numeric_fract_2,
+    "rgb(255.0, 10.4, 0.5)",
+    Color::new(255, 10, 1)
+);
+
+test_parse!(
+    rgb_space_separated_1,
+    "rgb(255 0 0)",
+    Color::new(255, 0, 0)
+);
+
+test_parse!(
+    rgb_space_separated_2,
+    "rgb(255 0 0 / 0.5)",
+    Color::new_rgba(255, 0, 0, 128)
+);
+
+test_parse!(
+    rgb_space_separated_3,
+    "rgb( 100% 0% 0%/50% )",
+    Color::new_rgba(255, 0, 0, 128)
+);
+
+test_parse!(
+    rgb_space_separated_4,
+    "rgb(10 20 30 / 2)",
+    Color::new_rgba(10, 20, 30, 255)
+);
+
+test_parse!(
+    rgb_space_separated_5,
+    "rgb(10 20 30 / 0)",
+    Color::new_rgba(10, 20, 30, 0)
+);
+
+test_error!(
+    rgb_alpha_err_1,
+    "rgb(255 0 0 / )",
+    "invalid number at 1:15"
+);
+
+test_error!(
+    rgb_alpha_err_2,
+    "rgb(255 0 0 / 0.5 0.5)",
+    "expected ')' not '0' at 1:19"
+);
+
+test_error!(
+    rgb_mixed_1,
+    "rgb(50%,10,10)",
+    "invalid color at 1:9"
+);
+
+test_error!(
+    rgb_mixed_2,
+    "rgb(300, 50%, 10)",
+    "invalid color at 1:10"
+);
+
+test_error!(
+    rgb_separator_err_1,
+    "rgb(1,2,3,)",
+    "invalid color at 1:10"
+);
+
+test_error!(
+    rgb_separator_err_2,
+    "rgb(1 2, 3)",
+    "invalid color at 1:8"
+);
+
+test_error!(
+    rgb_separator_err_3,
+    "rgb(1,2,3 / 0.5)",
+    "invalid color at 1:11"
+);
+
+test_error!(
+    rgb_invalid_unit,
+    "rgb(10px, 10, 10)",
+    "invalid color at 1:5"
+);
+
 test_error!(
     invalid_input_1,
     "rgb(-0\x0d",
@@ -150,3 +246,205 @@ test_error!(
     "#9ߞpx! ;",
     "invalid color at 1:1"
 );
+
+macro_rules! test_hsv {
+    ($name:ident, $h:expr, $s:expr, $v:expr, $color:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(Color::from_hsv($h, $s, $v), $color);
+        }
+    };
+}
+
+test_hsv!(hsv_red, 0.0, 1.0, 1.0, Color::new(255, 0, 0));
+test_hsv!(hsv_green, 120.0, 1.0, 1.0, Color::new(0, 255, 0));
+test_hsv!(hsv_blue, 240.0, 1.0, 1.0, Color::new(0, 0, 255));
+test_hsv!(hsv_yellow, 60.0, 1.0, 1.0, Color::new(255, 255, 0));
+test_hsv!(hsv_half_value, 120.0, 1.0, 0.5, Color::new(0, 128, 0));
+test_hsv!(hsv_gray, 200.0, 0.0, 0.5, Color::new(128, 128, 128));
+test_hsv!(hsv_white, 0.0, 0.0, 1.0, Color::new(255, 255, 255));
+test_hsv!(hsv_black, 90.0, 1.0, 0.0, Color::new(0, 0, 0));
+test_hsv!(hsv_wrap_1, 360.0, 1.0, 1.0, Color::new(255, 0, 0));
+test_hsv!(hsv_wrap_2, 480.0, 1.0, 1.0, Color::new(0, 255, 0));
+test_hsv!(hsv_wrap_3, -120.0, 1.0, 1.0, Color::new(0, 0, 255));
+test_hsv!(hsv_out_of_range, 0.0, 2.0, -1.0, Color::new(0, 0, 0));
+
+macro_rules! test_percent {
+    ($name:ident, $v:expr, $c:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(Color::channel_from_percent($v), $c);
+        }
+    };
+}
+
+test_percent!(percent_negative, -10.0, 0);
+test_percent!(percent_zero, 0.0, 0);
+test_percent!(percent_half, 50.0, 128);
+test_percent!(percent_full, 100.0, 255);
+test_percent!(percent_overflow, 150.0, 255);
+
+macro_rules! test_grayscale {
+    ($name:ident, $c:expr, $gray:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!($c.to_grayscale(), $gray);
+        }
+    };
+}
+
+test_grayscale!(grayscale_red, Color::new(255, 0, 0), Color::new(54, 54, 54));
+test_grayscale!(grayscale_green, Color::new(0, 255, 0), Color::new(182, 182, 182));
+test_grayscale!(grayscale_blue, Color::new(0, 0, 255), Color::new(18, 18, 18));
+test_grayscale!(grayscale_white, Color::new(255, 255, 255), Color::new(255, 255, 255));
+test_grayscale!(grayscale_alpha, Color::new_rgba(255, 0, 0, 128), Color::new_rgba(54, 54, 54, 128));
+
+test_parse!(
+    rgb_numeric_percents_negative,
+    "rgb(-10%, 50%, 150%)",
+    Color::new(0, 128, 255)
+);
+
+macro_rules! test_name_ci {
+    ($name:ident, $text:expr, $color:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(svgparser::rgb_color_from_name_ci($text), $color);
+        }
+    };
+}
+
+test_name_ci!(name_ci_1, "Red", Some(Color::new(255, 0, 0)));
+test_name_ci!(name_ci_2, "CORNFLOWERBLUE", Some(Color::new(100, 149, 237)));
+test_name_ci!(name_ci_3, "LightGoldenrodYellow", Some(Color::new(250, 250, 210)));
+test_name_ci!(name_ci_4, "unknown", None);
+test_name_ci!(name_ci_5, "", None);
+test_name_ci!(name_ci_6, "averyveryveryverylongcolorname", None);
+test_name_ci!(name_ci_7, "Rédd", None);
+
+test_parse!(
+    transparent,
+    "transparent",
+    Color::new_rgba(0, 0, 0, 0)
+);
+
+test_parse!(
+    transparent_upper,
+    " Transparent ",
+    Color::new_rgba(0, 0, 0, 0)
+);
+
+test_error!(
+    current_color,
+    "currentColor",
+    "invalid color at 1:1"
+);
+
+#[test]
+fn display_transparent() {
+    assert_eq!(Color::from_str("transparent").unwrap().to_string(), "transparent");
+    assert_eq!(Color::new(255, 0, 0).to_string(), "#ff0000");
+}
+
+#[test]
+fn display_alpha() {
+    let c = Color::from_str("rgb(255 0 0 / 50%)").unwrap();
+    assert_eq!(c.to_string(), "rgb(255 0 0 / 0.502)");
+    assert_eq!(Color::from_str(&c.to_string()).unwrap(), c);
+}
+
+#[test]
+fn display_alpha_round_trip() {
+    for a in 1..255 {
+        let c = Color::new_rgba(1, 2, 3, a);
+        assert_eq!(Color::from_str(&c.to_string()).unwrap(), c);
+    }
+}
+
+macro_rules! test_error_span {
+    ($name:ident, $text:expr, $start:expr, $end:expr) => {
+        #[test]
+        fn $name() {
+            match Color::from_str($text) {
+                Err(svgparser::StreamError::InvalidColorSpan(span)) => {
+                    assert_eq!(span.start.to_string(), $start);
+                    assert_eq!(span.end.to_string(), $end);
+                }
+                v => panic!("unexpected result: {:?}", v),
+            }
+        }
+    };
+}
+
+test_error_span!(error_span_1, " qwerty ", "1:2", "1:7");
+test_error_span!(error_span_2, "#1234", "1:1", "1:5");
+test_error_span!(error_span_3, "#", "1:1", "1:1");
+test_error_span!(error_span_4, "#CD853F icc-color(acmecmyk, 0.11, 0.48, 0.83, 0.00) ",
+                 "1:9", "1:51");
+
+macro_rules! test_with_name {
+    ($name:ident, $text:expr, $color:expr, $color_name:expr) => {
+        #[test]
+        fn $name() {
+            let v = Color::from_span_with_name(svgparser::xmlparser::StrSpan::from_str($text));
+            assert_eq!(v.unwrap(), ($color, $color_name));
+        }
+    };
+}
+
+test_with_name!(with_name_1, "red", Color::new(255, 0, 0), Some("red"));
+test_with_name!(with_name_2, "RED", Color::new(255, 0, 0), Some("red"));
+test_with_name!(with_name_3, " CornflowerBlue ", Color::new(100, 149, 237), Some("cornflowerblue"));
+test_with_name!(with_name_4, "Transparent", Color::new_rgba(0, 0, 0, 0), Some("transparent"));
+test_with_name!(with_name_5, "#f00", Color::new(255, 0, 0), None);
+test_with_name!(with_name_6, "rgb(255, 0, 0)", Color::new(255, 0, 0), None);
+
+macro_rules! test_to_name {
+    ($name:ident, $color:expr, $result:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!($color.to_name(), $result);
+        }
+    };
+}
+
+test_to_name!(to_name_1, Color::new(255, 0, 0), Some("red"));
+test_to_name!(to_name_2, Color::new(1, 2, 3), None);
+test_to_name!(to_name_3, Color::new(128, 128, 128), Some("gray"));
+test_to_name!(to_name_4, Color::new(0, 255, 255), Some("aqua"));
+test_to_name!(to_name_5, Color::new_rgba(255, 0, 0, 128), None);
+
+#[test]
+fn consts_1() {
+    assert_eq!(Color::BLACK, Color::from_str("black").unwrap());
+    assert_eq!(Color::WHITE, Color::from_str("white").unwrap());
+    assert_eq!(Color::RED, Color::from_str("red").unwrap());
+    assert_eq!(Color::LIME, Color::from_str("lime").unwrap());
+    assert_eq!(Color::BLUE, Color::from_str("blue").unwrap());
+    assert_eq!(Color::TRANSPARENT, Color::from_str("transparent").unwrap());
+}
+
+#[test]
+fn over_1() {
+    let c = Color::new_rgba(255, 0, 0, 128).over(Color::WHITE);
+    assert_eq!(c, Color::new(255, 127, 127));
+}
+
+#[test]
+fn over_2() {
+    assert_eq!(Color::RED.over(Color::WHITE), Color::RED);
+    assert_eq!(Color::TRANSPARENT.over(Color::WHITE), Color::WHITE);
+    assert_eq!(Color::TRANSPARENT.over(Color::TRANSPARENT), Color::TRANSPARENT);
+}
+
+#[test]
+fn lerp_1() {
+    assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color::new(128, 128, 128));
+}
+
+#[test]
+fn lerp_2() {
+    assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.0), Color::BLACK);
+    assert_eq!(Color::BLACK.lerp(Color::WHITE, 2.0), Color::WHITE);
+    assert_eq!(Color::RED.lerp(Color::TRANSPARENT, 0.5), Color::new_rgba(128, 0, 0, 128));
+}
@@ -0,0 +1,96 @@
+extern crate svgparser;
+
+use svgparser::{
+    xmlparser,
+    ErrorPosExt,
+    ErrorSpan,
+    LineIndex,
+    NumberList,
+};
+
+use xmlparser::{
+    ErrorPos,
+    FromSpan,
+    Stream,
+};
+
+#[test]
+fn line_index_1() {
+    let mut index = LineIndex::new("");
+    assert_eq!(index.error_pos(0), ErrorPos::new(1, 1));
+}
+
+#[test]
+fn line_index_2() {
+    let text = "a\n\nbc\n";
+    let mut index = LineIndex::new(text);
+    let mut s = Stream::from_str(text);
+    for offset in 0..(text.len() + 1) {
+        assert_eq!(index.error_pos(offset), s.gen_error_pos_from(offset), "offset {}", offset);
+    }
+
+    assert_eq!(index.error_pos(0), ErrorPos::new(1, 1));
+    assert_eq!(index.error_pos(4), ErrorPos::new(3, 2));
+}
+
+#[test]
+fn line_index_same_as_stream() {
+    let mut text = String::new();
+    for i in 0..2000 {
+        for _ in 0..(i % 7) {
+            text.push('x');
+        }
+        text.push('\n');
+    }
+
+    let mut index = LineIndex::new(&text);
+    let mut s = Stream::from_str(&text);
+    for offset in (0..text.len()).filter(|n| n % 13 == 0) {
+        assert_eq!(index.error_pos(offset), s.gen_error_pos_from(offset), "offset {}", offset);
+    }
+}
+
+#[test]
+fn error_pos_display_1() {
+    assert_eq!(ErrorPos::new(1, 1).to_string(), "1:1");
+    assert_eq!(ErrorPos::new(12, 345).to_string(), "12:345");
+    assert_eq!(ErrorSpan::new(ErrorPos::new(2, 3), ErrorPos::new(2, 5)).to_string(), "2:3");
+}
+
+#[test]
+fn offset_by_1() {
+    let base = ErrorPos::new(1, 1);
+    assert_eq!(ErrorPos::new(1, 1).offset_by(base), ErrorPos::new(1, 1));
+    assert_eq!(ErrorPos::new(2, 7).offset_by(base), ErrorPos::new(2, 7));
+}
+
+#[test]
+fn offset_by_2() {
+    let base = ErrorPos::new(3, 10);
+    assert_eq!(ErrorPos::new(1, 1).offset_by(base), ErrorPos::new(3, 10));
+    assert_eq!(ErrorPos::new(1, 5).offset_by(base), ErrorPos::new(3, 14));
+    assert_eq!(ErrorPos::new(3, 2).offset_by(base), ErrorPos::new(5, 2));
+}
+
+#[test]
+fn offset_by_3() {
+    let span = ErrorSpan::new(ErrorPos::new(1, 2), ErrorPos::new(2, 1));
+    assert_eq!(span.offset_by(ErrorPos::new(4, 8)),
+               ErrorSpan::new(ErrorPos::new(4, 9), ErrorPos::new(5, 1)));
+}
+
+#[test]
+fn offset_by_4() {
+    // A value extracted from the 'points="1 2 x"' attribute on the second line.
+    let text = "<svg>\n<polyline points=\"1 2 x\"/>";
+    let mut doc = Stream::from_str(text);
+    let base = doc.gen_error_pos_from(text.find('1').unwrap());
+
+    let value = String::from("1 2 x");
+    let err = NumberList::from_str(&value).try_collect().unwrap_err();
+    assert_eq!(err.to_string(), "invalid number at 1:5");
+
+    let pos = ErrorPos::new(1, 5).offset_by(base);
+    assert_eq!(pos, doc.gen_error_pos_from(text.find('x').unwrap()));
+    assert_eq!(pos.to_string(), "2:23");
+}
@@ -0,0 +1,86 @@
+extern crate svgparser;
+
+use svgparser::{
+    FontShorthand,
+    FontSize,
+    Length,
+    LengthUnit,
+    ValueId,
+};
+use svgparser::xmlparser::{
+    StrSpan,
+};
+
+fn parse<'a>(text: &'a str) -> Result<FontShorthand<'a>, svgparser::StreamError> {
+    FontShorthand::from_span(StrSpan::from_str(text))
+}
+
+#[test]
+fn full() {
+    let font = parse("italic small-caps bold 12px/1.5 'Times New Roman', serif").unwrap();
+    assert_eq!(font, FontShorthand {
+        style: Some(ValueId::Italic),
+        variant: Some(ValueId::SmallCaps),
+        weight: Some(ValueId::Bold),
+        size: FontSize::Length(Length::new(12.0, LengthUnit::Px)),
+        line_height: Some(Length::new(1.5, LengthUnit::None)),
+        family: "'Times New Roman', serif",
+    });
+}
+
+#[test]
+fn minimal() {
+    let font = parse("12px Arial").unwrap();
+    assert_eq!(font, FontShorthand {
+        style: None,
+        variant: None,
+        weight: None,
+        size: FontSize::Length(Length::new(12.0, LengthUnit::Px)),
+        line_height: None,
+        family: "Arial",
+    });
+}
+
+#[test]
+fn any_order() {
+    let font = parse("700 normal oblique large / normal sans-serif").unwrap();
+    assert_eq!(font.style, Some(ValueId::Oblique));
+    assert_eq!(font.variant, None);
+    assert_eq!(font.weight, Some(ValueId::N700));
+    assert_eq!(font.size, FontSize::PredefValue(ValueId::Large));
+    assert_eq!(font.line_height, None);
+    assert_eq!(font.family, "sans-serif");
+}
+
+#[test]
+fn line_height_length() {
+    let font = parse("bold 1em/20px Arial").unwrap();
+    assert_eq!(font.size, FontSize::Length(Length::new(1.0, LengthUnit::Em)));
+    assert_eq!(font.line_height, Some(Length::new(20.0, LengthUnit::Px)));
+}
+
+#[test]
+fn tabs_and_new_lines() {
+    let font = parse("bold\t12px\nserif").unwrap();
+    assert_eq!(font.weight, Some(ValueId::Bold));
+    assert_eq!(font.size, FontSize::Length(Length::new(12.0, LengthUnit::Px)));
+    assert_eq!(font.family, "serif");
+}
+
+#[test]
+fn err_no_family() {
+    assert_eq!(parse("bold 12px").unwrap_err().to_string(),
+               "unexpected end of stream");
+}
+
+#[test]
+fn err_no_size() {
+    assert_eq!(parse("italic Arial").unwrap_err().to_string(),
+               "invalid number at 1:8");
+}
+
+#[test]
+fn err_empty() {
+    assert_eq!(parse("").unwrap_err().to_string(),
+               "unexpected end of stream");
+}
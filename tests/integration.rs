@@ -0,0 +1,153 @@
+extern crate svgparser;
+
+use svgparser::{
+    path,
+    style,
+    svg,
+    transform,
+    xmlparser,
+    AttributeId as AId,
+    AttributeValue as AV,
+    Color,
+    ElementId as EId,
+    FuncIRI,
+    Length,
+    LengthUnit,
+    ViewBox,
+};
+
+use xmlparser::{
+    FromSpan,
+};
+
+type Attribute<'a> = (EId, AId, AV<'a>);
+
+// Parses a whole file into a flat list of SVG attributes.
+// Attributes from the `style` attribute are inlined.
+fn parse_file<'a>(text: &'a str) -> (Vec<EId>, Vec<Attribute<'a>>) {
+    let mut elements = Vec::new();
+    let mut attributes = Vec::new();
+    let mut curr_eid = None;
+
+    for token in svg::Tokenizer::from_str(text) {
+        match token.unwrap() {
+            svg::Token::ElementStart(tag_name) => {
+                curr_eid = match tag_name.local {
+                    svg::Name::Svg(eid) => Some(eid),
+                    svg::Name::Xml(_) => None,
+                };
+
+                if let Some(eid) = curr_eid {
+                    elements.push(eid);
+                }
+            }
+            svg::Token::Attribute(name, value) => {
+                let eid = match curr_eid {
+                    Some(eid) => eid,
+                    None => continue,
+                };
+
+                let aid = match name.local {
+                    svg::Name::Svg(aid) => aid,
+                    svg::Name::Xml(_) => continue,
+                };
+
+                match AV::from_span(eid, name.prefix, aid, value).unwrap() {
+                    AV::Style(tokenizer) => {
+                        for token in tokenizer {
+                            if let style::Token::SvgAttribute(aid, value, _) = token.unwrap() {
+                                let av = AV::from_span(eid, "", aid, value).unwrap();
+                                attributes.push((eid, aid, av));
+                            }
+                        }
+                    }
+                    av => attributes.push((eid, aid, av)),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (elements, attributes)
+}
+
+fn find<'a>(attributes: &[Attribute<'a>], eid: EId, aid: AId) -> Vec<AV<'a>> {
+    attributes.iter()
+              .filter(|a| a.0 == eid && a.1 == aid)
+              .map(|a| a.2)
+              .collect()
+}
+
+#[test]
+fn icon_1() {
+    let text = include_str!("files/icon.svg");
+    let (elements, attributes) = parse_file(text);
+
+    assert_eq!(elements, vec![
+        EId::Svg, EId::Defs, EId::LinearGradient, EId::Stop, EId::Stop,
+        EId::RadialGradient, EId::G, EId::Rect, EId::Path, EId::Circle,
+    ]);
+
+    // Root element.
+    assert_eq!(find(&attributes, EId::Svg, AId::ViewBox),
+               vec![AV::ViewBox(ViewBox::new(0.0, 0.0, 48.0, 48.0))]);
+    assert_eq!(find(&attributes, EId::Svg, AId::Width),
+               vec![AV::Length(Length::new(48.0, LengthUnit::None))]);
+
+    // Gradients.
+    assert_eq!(find(&attributes, EId::Stop, AId::StopColor), vec![
+        AV::Color(Color::new(0x4a, 0x90, 0xd9)),
+        AV::Color(Color::new(32, 74, 135)),
+    ]);
+    assert_eq!(find(&attributes, EId::Stop, AId::StopOpacity), vec![AV::Number(0.8)]);
+    assert_eq!(find(&attributes, EId::RadialGradient, AId::Href), vec![AV::IRI("lg1")]);
+
+    // Styles.
+    assert_eq!(find(&attributes, EId::Rect, AId::Fill), vec![AV::FuncIRI(FuncIRI::local("lg1"))]);
+    assert_eq!(find(&attributes, EId::Rect, AId::Stroke),
+               vec![AV::Color(Color::new(0x20, 0x4a, 0x87))]);
+    assert_eq!(find(&attributes, EId::Rect, AId::StrokeWidth),
+               vec![AV::Length(Length::new(1.5, LengthUnit::None))]);
+    assert_eq!(find(&attributes, EId::Path, AId::Fill),
+               vec![AV::Color(Color::new(255, 255, 255))]);
+}
+
+#[test]
+fn icon_path() {
+    let text = include_str!("files/icon.svg");
+    let (_, attributes) = parse_file(text);
+
+    let tokens: Vec<_> = match find(&attributes, EId::Path, AId::D)[0] {
+        AV::Path(tokenizer) => tokenizer.collect(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(tokens.len(), 10);
+    assert_eq!(tokens[0], path::Token::MoveTo { abs: true, x: 12.0, y: 1020.3622 });
+    assert_eq!(tokens[2], path::Token::LineTo { abs: false, x: 0.0, y: 4.0 });
+    assert_eq!(tokens[4], path::Token::ClosePath { abs: false });
+    assert_eq!(tokens[7], path::Token::VerticalLineTo { abs: false, y: 4.0 });
+    assert_eq!(tokens[9], path::Token::ClosePath { abs: true });
+}
+
+#[test]
+fn icon_transforms() {
+    let text = include_str!("files/icon.svg");
+    let (_, attributes) = parse_file(text);
+
+    let collect = |av: &AV| -> Vec<transform::Token> {
+        match *av {
+            AV::Transform(tokenizer) => tokenizer.map(|t| t.unwrap()).collect(),
+            _ => unreachable!(),
+        }
+    };
+
+    assert_eq!(collect(&find(&attributes, EId::G, AId::Transform)[0]),
+               vec![transform::Token::Translate { tx: 0.0, ty: -1004.3622 }]);
+
+    assert_eq!(collect(&find(&attributes, EId::RadialGradient, AId::GradientTransform)[0]),
+               vec![transform::Token::Matrix { a: 1.0, b: 0.0, c: 0.0, d: 0.5, e: 0.0, f: 12.0 }]);
+
+    let ts = collect(&find(&attributes, EId::Circle, AId::Transform)[0]);
+    assert!(ts.contains(&transform::Token::Rotate { angle: 45.0 }));
+}
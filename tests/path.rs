@@ -4,6 +4,8 @@ use svgparser::{
     xmlparser,
 };
 use svgparser::path::{
+    flatten,
+    FlatToken,
     Tokenizer,
     Token,
 };
@@ -226,7 +228,34 @@ test!(close_path_3, "M10 20 L 30 40 Z Z Z",
     Token::ClosePath { abs: true }
 );
 
-// first token should be EndOfStream
+macro_rules! test_implicit {
+    ($name:ident, $text:expr, $( $flag:expr ),*) => (
+        #[test]
+        fn $name() {
+            let mut s = Tokenizer::from_str($text);
+            $(
+                assert!(s.next().is_some());
+                assert_eq!(s.is_implicit(), $flag);
+            )*
+            assert_eq!(s.next(), None);
+        }
+    )
+}
+
+test_implicit!(implicit_1, "M 1 2 3 4", false, true);
+test_implicit!(implicit_2, "M 1 2 L 3 4", false, false);
+test_implicit!(implicit_3, "M 1 2 L 3 4 5 6 Z", false, false, true, false);
+test_implicit!(implicit_4, "m 1 2 3 4 M 5 6", false, true, false);
+
+#[test]
+fn implicit_5() {
+    let mut s = Tokenizer::from_str("M 1 2 3 4");
+    s.next();
+    assert_eq!(s.next().unwrap(), Token::LineTo { abs: true, x: 3.0, y: 4.0 });
+    assert!(s.is_implicit());
+}
+
+// no tokens should be parsed
 test!(invalid_1, "M\t.", );
 
 // ClosePath can't be followed by a number
@@ -241,3 +270,226 @@ test!(invalid_3, "M 0 0 Z H 10",
     Token::ClosePath { abs: true },
     Token::HorizontalLineTo { abs: true, x: 10.0 }
 );
+
+fn flatten_all(text: &str, tolerance: f64) -> Vec<FlatToken> {
+    flatten(Tokenizer::from_str(text), tolerance).collect()
+}
+
+fn coords(t: &FlatToken) -> (f64, f64) {
+    match *t {
+        FlatToken::MoveTo { x, y } | FlatToken::LineTo { x, y } => (x, y),
+    }
+}
+
+#[test]
+fn flatten_lines() {
+    assert_eq!(flatten_all("M 10 20 L 30 40 h 10 v -10 Z", 0.1), vec![
+        FlatToken::MoveTo { x: 10.0, y: 20.0 },
+        FlatToken::LineTo { x: 30.0, y: 40.0 },
+        FlatToken::LineTo { x: 40.0, y: 40.0 },
+        FlatToken::LineTo { x: 40.0, y: 30.0 },
+        FlatToken::LineTo { x: 10.0, y: 20.0 },
+    ]);
+}
+
+#[test]
+fn flatten_subpaths() {
+    assert_eq!(flatten_all("M 10 20 L 30 40 Z m 5 5 l 1 1", 0.1), vec![
+        FlatToken::MoveTo { x: 10.0, y: 20.0 },
+        FlatToken::LineTo { x: 30.0, y: 40.0 },
+        FlatToken::LineTo { x: 10.0, y: 20.0 },
+        FlatToken::MoveTo { x: 15.0, y: 25.0 },
+        FlatToken::LineTo { x: 16.0, y: 26.0 },
+    ]);
+}
+
+#[test]
+fn flatten_quarter_circle_curve() {
+    // A cubic approximation of a quarter circle with the center at (0, 100).
+    let path = "M 0 0 C 55.228 0 100 44.772 100 100";
+    let points = flatten_all(path, 0.1);
+
+    assert_eq!(points[0], FlatToken::MoveTo { x: 0.0, y: 0.0 });
+    assert_eq!(*points.last().unwrap(), FlatToken::LineTo { x: 100.0, y: 100.0 });
+    assert!(points.len() > 10 && points.len() < 40);
+
+    for p in &points {
+        let (x, y) = coords(p);
+        let r = x.hypot(y - 100.0);
+        assert!((r - 100.0).abs() < 0.1, "{:?}", p);
+    }
+
+    // A bigger tolerance produces less points.
+    let coarse = flatten_all(path, 5.0);
+    assert!(coarse.len() < points.len());
+    assert_eq!(*coarse.last().unwrap(), FlatToken::LineTo { x: 100.0, y: 100.0 });
+}
+
+#[test]
+fn flatten_smooth_curves() {
+    let points = flatten_all("M 0 0 Q 10 10 20 0 T 40 0", 0.01);
+    assert_eq!(*points.last().unwrap(), FlatToken::LineTo { x: 40.0, y: 0.0 });
+
+    // The reflected control point is at (30, -10), so the second curve is below the x-axis.
+    let (_, y) = coords(&points[points.len() - points.len() / 4]);
+    assert!(y < 0.0);
+}
+
+#[test]
+fn flatten_arc() {
+    let points = flatten_all("M 0 0 A 50 50 0 0 1 100 0", 0.05);
+    assert_eq!(*points.last().unwrap(), FlatToken::LineTo { x: 100.0, y: 0.0 });
+    assert!(points.len() > 10);
+
+    for p in &points {
+        let (x, y) = coords(p);
+        assert!(((x - 50.0).hypot(y) - 50.0).abs() < 1e-6, "{:?}", p);
+        // Positive sweep goes through the negative y in this case.
+        assert!(y <= 1e-9, "{:?}", p);
+    }
+}
+
+#[test]
+fn flatten_arc_zero_radius() {
+    assert_eq!(flatten_all("M 0 0 A 0 50 0 0 1 100 0", 0.1), vec![
+        FlatToken::MoveTo { x: 0.0, y: 0.0 },
+        FlatToken::LineTo { x: 100.0, y: 0.0 },
+    ]);
+}
+
+#[test]
+fn approx_eq_near() {
+    let t1 = Token::CurveTo { abs: true, x1: 1.0, y1: 2.0, x2: 3.0, y2: 4.0, x: 5.0, y: 6.0 };
+    let t2 = Token::CurveTo { abs: true, x1: 1.0 + 1e-9, y1: 2.0, x2: 3.0, y2: 4.0 - 1e-9, x: 5.0, y: 6.0 };
+    assert!(t1.approx_eq(&t2, 1e-6));
+    assert!(!t1.approx_eq(&t2, 1e-12));
+}
+
+#[test]
+fn approx_eq_different() {
+    let t1 = Token::LineTo { abs: true, x: 10.0, y: 20.0 };
+    assert!(!t1.approx_eq(&Token::LineTo { abs: false, x: 10.0, y: 20.0 }, 1.0));
+    assert!(!t1.approx_eq(&Token::MoveTo { abs: true, x: 10.0, y: 20.0 }, 1.0));
+    assert!(!t1.approx_eq(&Token::LineTo { abs: true, x: 10.0, y: 25.0 }, 1.0));
+}
+
+#[test]
+fn approx_eq_arc() {
+    let t1 = Token::EllipticalArc {
+        abs: true, rx: 5.0, ry: 6.0, x_axis_rotation: 30.0,
+        large_arc: false, sweep: true, x: 10.0, y: 20.0,
+    };
+    let t2 = Token::EllipticalArc {
+        abs: true, rx: 5.0, ry: 6.0, x_axis_rotation: 30.0 + 1e-9,
+        large_arc: false, sweep: true, x: 10.0, y: 20.0,
+    };
+    let t3 = Token::EllipticalArc {
+        abs: true, rx: 5.0, ry: 6.0, x_axis_rotation: 30.0,
+        large_arc: true, sweep: true, x: 10.0, y: 20.0,
+    };
+    assert!(t1.approx_eq(&t2, 1e-6));
+    // flags are compared exactly
+    assert!(!t1.approx_eq(&t3, 1.0));
+}
+
+#[test]
+fn remaining_1() {
+    let mut p = Tokenizer::from_str("M 10 20 L 30 40");
+    assert_eq!(p.remaining().to_str(), "M 10 20 L 30 40");
+    p.next();
+    p.next();
+    assert_eq!(p.remaining().to_str(), "");
+}
+
+#[test]
+fn empty_input_1() {
+    let mut p = Tokenizer::from_str("");
+    assert!(p.is_empty_input());
+    assert!(p.next().is_none());
+}
+
+#[test]
+fn empty_input_2() {
+    let p = Tokenizer::from_str(" \n\t ");
+    assert!(p.is_empty_input());
+}
+
+#[test]
+fn empty_input_3() {
+    let mut p = Tokenizer::from_str("!!!");
+    assert!(p.next().is_none());
+    assert!(!p.is_empty_input());
+}
+
+macro_rules! test_checked_err {
+    ($name:ident, $text:expr, $err:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = Tokenizer::from_str($text);
+            loop {
+                match s.next_checked() {
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        assert_eq!(e.to_string(), $err);
+                        break;
+                    }
+                    None => panic!("no error"),
+                }
+            }
+
+            assert!(s.next_checked().is_none());
+        }
+    )
+}
+
+test_checked_err!(checked_err_1, "M 10 20 A 5 5 0 2 0 10 10", "invalid arc flag at 1:17");
+test_checked_err!(checked_err_2, "M 10 20 A 5 5 0 1 a 10 10", "invalid arc flag at 1:19");
+test_checked_err!(checked_err_3, "L 10 20", "expected 'Mm' not 'L' at 1:1");
+test_checked_err!(checked_err_4, "M 10 20 L 30 #", "invalid number at 1:14");
+test_checked_err!(checked_err_5, "M 10 20 Z 30 40", "expected 'a command' not '3' at 1:11");
+
+#[test]
+fn checked_arc_flags() {
+    let mut s = Tokenizer::from_str("M 10 20 A 5 5 0 10 10 10");
+    s.next_checked();
+    assert_eq!(s.next_checked().unwrap().unwrap(), Token::EllipticalArc {
+        abs: true, rx: 5.0, ry: 5.0, x_axis_rotation: 0.0,
+        large_arc: true, sweep: false, x: 10.0, y: 10.0,
+    });
+    assert!(s.next_checked().is_none());
+}
+
+#[test]
+fn invalid_arc_flag_stops() {
+    let mut s = Tokenizer::from_str("M 10 20 A 5 5 0 2 0 10 10 L 30 40");
+    assert!(s.next().is_some());
+    assert!(s.next().is_none());
+    assert!(s.next().is_none());
+}
+
+#[test]
+fn restart_1() {
+    let mut p = Tokenizer::from_str("M 10 20 L 30 40 Z");
+    let count = p.by_ref().count();
+    assert_eq!(count, 3);
+    assert!(p.next().is_none());
+
+    let tokens: Vec<_> = p.restart().collect();
+    assert_eq!(tokens.len(), count);
+    assert_eq!(tokens[0], Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+    assert_eq!(tokens[1], Token::LineTo { abs: true, x: 30.0, y: 40.0 });
+    assert_eq!(tokens[2], Token::ClosePath { abs: true });
+}
+
+#[test]
+fn restart_2() {
+    // Implicit commands state is reset too.
+    let mut p = Tokenizer::from_str("M 10 20 30 40");
+    p.next();
+    p.next();
+    assert!(p.is_implicit());
+
+    let mut p = p.restart();
+    assert!(!p.is_implicit());
+    assert_eq!(p.next().unwrap(), Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+}
@@ -37,3 +37,39 @@ test!(points_err_2, "1 2 3",
 test!(points_err_4, "1 2 3 t",
     (1.0, 2.0)
 );
+
+#[test]
+fn count_points_1() {
+    let pts = Points::from_str("1,2 3,4 5,6");
+    assert_eq!(pts.count_points(), 3);
+    assert_eq!(pts.count(), 3);
+}
+
+#[test]
+fn count_points_2() {
+    let mut pts = Points::from_str("1 2 3 4 5");
+    assert_eq!(pts.count_points(), 2);
+    assert_eq!(pts.next().unwrap(), (1.0, 2.0));
+    assert_eq!(pts.count_points(), 1);
+}
+
+#[test]
+fn count_points_3() {
+    assert_eq!(Points::from_str("").count_points(), 0);
+    assert_eq!(Points::from_str("1 2 3 t 5 6").count_points(), 1);
+}
+
+#[test]
+fn fused_1() {
+    let mut pts = Points::from_str("1 2 3 t 5 6");
+    assert_eq!(pts.next().unwrap(), (1.0, 2.0));
+    assert!(pts.next().is_none());
+    assert!(pts.next().is_none());
+}
+
+#[test]
+fn size_hint_1() {
+    assert_eq!(Points::from_str("").size_hint(), (0, Some(0)));
+    assert_eq!(Points::from_str("1,2").size_hint(), (0, Some(1)));
+    assert_eq!(Points::from_str("1-2-3-4").size_hint(), (0, Some(2)));
+}
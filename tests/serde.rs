@@ -0,0 +1,56 @@
+#![cfg(feature = "serde")]
+
+extern crate serde_json;
+extern crate svgparser;
+
+use svgparser::{
+    Align,
+    AspectRatio,
+    Color,
+    Length,
+    LengthUnit,
+    ViewBox,
+};
+
+macro_rules! test_round_trip {
+    ($name:ident, $t:ty, $value:expr, $json:expr) => (
+        #[test]
+        fn $name() {
+            let v: $t = $value;
+            let json = serde_json::to_string(&v).unwrap();
+            assert_eq!(json, $json);
+            assert_eq!(serde_json::from_str::<$t>(&json).unwrap(), v);
+        }
+    )
+}
+
+test_round_trip!(color_1, Color, Color::new(255, 0, 16), "\"#ff0010\"");
+test_round_trip!(color_2, Color, Color::new_rgba(255, 0, 0, 128), "\"rgb(255 0 0 / 0.502)\"");
+test_round_trip!(color_3, Color, Color::TRANSPARENT, "\"transparent\"");
+
+test_round_trip!(length_1, Length, Length::new(30.0, LengthUnit::Percent), "\"30%\"");
+test_round_trip!(length_2, Length, Length::new(1.5, LengthUnit::Px), "\"1.5px\"");
+test_round_trip!(length_3, Length, Length::new(-2.0, LengthUnit::None), "\"-2\"");
+
+test_round_trip!(view_box_1, ViewBox, ViewBox::new(0.0, -10.0, 100.0, 50.5),
+                 "[0.0,-10.0,100.0,50.5]");
+
+test_round_trip!(aspect_ratio_1, AspectRatio,
+    AspectRatio { defer: false, align: Align::XMidYMid, slice: false }, "\"xMidYMid\"");
+test_round_trip!(aspect_ratio_2, AspectRatio,
+    AspectRatio { defer: true, align: Align::None, slice: true }, "\"defer none slice\"");
+
+#[test]
+fn color_from_name() {
+    assert_eq!(serde_json::from_str::<Color>("\"red\"").unwrap(), Color::new(255, 0, 0));
+}
+
+#[test]
+fn length_err_1() {
+    assert!(serde_json::from_str::<Length>("\"10px text\"").is_err());
+}
+
+#[test]
+fn view_box_err_1() {
+    assert!(serde_json::from_str::<ViewBox>("[0, 0, 0, 10]").is_err());
+}
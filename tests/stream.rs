@@ -1,5 +1,7 @@
 extern crate svgparser;
 
+use std::cmp::Ordering;
+
 use svgparser::{
     xmlparser,
     Length,
@@ -69,6 +71,23 @@ test_number_err!(number_err_8, "-99999999e99999999", "invalid number at 1:1");
 
 // ---
 
+macro_rules! test_number_or_percent {
+    ($name:ident, $text:expr, $result:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = Stream::from_str($text);
+            assert_eq!(s.parse_number_or_percent().unwrap(), $result);
+        }
+    )
+}
+
+test_number_or_percent!(number_or_percent_1, "50%", 0.5);
+test_number_or_percent!(number_or_percent_2, "0.5", 0.5);
+test_number_or_percent!(number_or_percent_3, "-20%", -0.2);
+test_number_or_percent!(number_or_percent_4, "2 %", 2.0);
+
+// ---
+
 macro_rules! test_length {
     ($name:ident, $text:expr, $result:expr) => (
         #[test]
@@ -95,6 +114,26 @@ test_length!(length_13, "1 1", Length::new(1.0, LengthUnit::None));
 test_length!(length_14, "1e0", Length::new(1.0, LengthUnit::None));
 test_length!(length_15, "1.0e0", Length::new(1.0, LengthUnit::None));
 test_length!(length_16, "1.0e0em", Length::new(1.0, LengthUnit::Em));
+test_length!(length_17, "1e2px", Length::new(100.0, LengthUnit::Px));
+test_length!(length_18, "1ex", Length::new(1.0, LengthUnit::Ex));
+test_length!(length_19, "1e+2em", Length::new(100.0, LengthUnit::Em));
+test_length!(length_20, "1E-3em", Length::new(0.001, LengthUnit::Em));
+test_length!(length_21, "1.5ex", Length::new(1.5, LengthUnit::Ex));
+test_length!(length_22, "2.e1mm", Length::new(20.0, LengthUnit::Mm));
+
+#[test]
+fn number_exp_unit_1() {
+    let mut s = Stream::from_str("1e2px");
+    assert_eq!(s.parse_number().unwrap(), 100.0);
+    assert_eq!(s.slice_tail().to_str(), "px");
+}
+
+#[test]
+fn number_exp_unit_2() {
+    let mut s = Stream::from_str("1em");
+    assert_eq!(s.parse_number().unwrap(), 1.0);
+    assert_eq!(s.slice_tail().to_str(), "em");
+}
 
 #[test]
 fn length_err_1() {
@@ -104,6 +143,105 @@ fn length_err_1() {
                "invalid number at 1:2");
 }
 
+macro_rules! test_length_err {
+    ($name:ident, $text:expr, $err:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = Stream::from_str($text);
+            assert_eq!(s.parse_length().unwrap_err().to_string(), $err);
+        }
+    )
+}
+
+// overflow should not produce an infinite length
+test_length_err!(length_err_2, "1e999px", "invalid number at 1:1");
+test_length_err!(length_err_3, "-1e999px", "invalid number at 1:1");
+test_length_err!(length_err_4, "NaNpx", "invalid number at 1:1");
+test_length_err!(length_err_5, "infpx", "invalid number at 1:1");
+test_length_err!(length_err_6, "+infpx", "invalid number at 1:1");
+
+macro_rules! test_length_strict {
+    ($name:ident, $text:expr, $result:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = Stream::from_str($text);
+            assert_eq!(s.parse_length_strict().unwrap(), $result);
+        }
+    )
+}
+
+test_length_strict!(length_strict_1, "5px", Length::new(5.0, LengthUnit::Px));
+test_length_strict!(length_strict_2, "5 ", Length::new(5.0, LengthUnit::None));
+test_length_strict!(length_strict_3, "5", Length::new(5.0, LengthUnit::None));
+test_length_strict!(length_strict_4, "5%,6", Length::new(5.0, LengthUnit::Percent));
+
+macro_rules! test_length_strict_err {
+    ($name:ident, $text:expr, $err:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = Stream::from_str($text);
+            assert_eq!(s.parse_length_strict().unwrap_err().to_string(), $err);
+        }
+    )
+}
+
+test_length_strict_err!(length_strict_err_1, "5foo", "invalid length at 1:2");
+test_length_strict_err!(length_strict_err_2, "5pxx", "invalid length at 1:4");
+test_length_strict_err!(length_strict_err_3, "5PX", "invalid length at 1:2");
+
+#[test]
+fn length_measured_1() {
+    let mut s = Stream::from_str("30%");
+    assert_eq!(s.parse_length_measured().unwrap(), (Length::new(30.0, LengthUnit::Percent), 3));
+}
+
+#[test]
+fn length_measured_2() {
+    // no unit
+    let mut s = Stream::from_str("1.5e2 mm");
+    assert_eq!(s.parse_length_measured().unwrap(), (Length::new(150.0, LengthUnit::None), 5));
+}
+
+#[test]
+fn length_measured_3() {
+    let mut s = Stream::from_str("  10px,");
+    assert_eq!(s.parse_length_measured().unwrap(), (Length::new(10.0, LengthUnit::Px), 6));
+    assert_eq!(s.pos(), 6);
+}
+
+#[test]
+fn length_from_str() {
+    assert_eq!("30%".parse::<Length>().unwrap(), Length::new(30.0, LengthUnit::Percent));
+    assert_eq!(" 5mm ".parse::<Length>().unwrap(), Length::new(5.0, LengthUnit::Mm));
+    assert_eq!("5 6".parse::<Length>().unwrap_err().to_string(), "invalid length at 1:3");
+    assert_eq!("5q".parse::<Length>().unwrap_err().to_string(), "invalid length at 1:2");
+}
+
+#[test]
+fn length_approx_eq() {
+    let l1 = Length::new(0.3, LengthUnit::Mm);
+    let l2 = Length::new(0.1 + 0.2, LengthUnit::Mm);
+    assert_ne!(l1, l2);
+    assert!(l1.approx_eq(&l2, 1e-10));
+    assert!(!l1.approx_eq(&Length::new(0.3, LengthUnit::Cm), 1e-10));
+}
+
+#[test]
+fn length_cmp_1() {
+    let l1 = Length::new(1.0, LengthUnit::Px);
+    let l2 = Length::new(2.0, LengthUnit::Px);
+    assert!(l1 < l2);
+    assert!(l2 >= l1);
+    assert_eq!(l1.partial_cmp(&l1), Some(Ordering::Equal));
+}
+
+#[test]
+fn length_cmp_2() {
+    let l1 = Length::new(1.0, LengthUnit::Px);
+    let l2 = Length::new(2.0, LengthUnit::Em);
+    assert_eq!(l1.partial_cmp(&l2), None);
+}
+
 // ---
 
 #[test]
@@ -119,3 +257,231 @@ fn integer_err_1() {
     assert_eq!(s.parse_integer().unwrap_err().to_string(),
                "invalid number at 1:1");
 }
+
+// ---
+
+#[test]
+fn numbers_into_1() {
+    let mut buf = [0.0; 2];
+    let mut s = Stream::from_str("2.5 3");
+    assert_eq!(s.parse_numbers_into(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [2.5, 3.0]);
+}
+
+#[test]
+fn numbers_into_2() {
+    let mut buf = [0.0; 2];
+    let mut s = Stream::from_str("2.5");
+    assert_eq!(s.parse_numbers_into(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], 2.5);
+}
+
+#[test]
+fn numbers_into_3() {
+    let mut buf = [0.0; 2];
+    let mut s = Stream::from_str("  ");
+    assert_eq!(s.parse_numbers_into(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn numbers_into_4() {
+    let mut buf = [0.0; 2];
+    let mut s = Stream::from_str("1, 2, 3");
+    assert_eq!(s.parse_numbers_into(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [1.0, 2.0]);
+    assert_eq!(s.slice_tail().to_str(), " 3");
+}
+
+#[test]
+fn numbers_into_err_1() {
+    let mut buf = [0.0; 2];
+    let mut s = Stream::from_str("1 x");
+    assert_eq!(s.parse_numbers_into(&mut buf).unwrap_err().to_string(),
+               "invalid number at 1:3");
+}
+
+#[test]
+fn list_number_end_1() {
+    let mut s = Stream::from_str("  ");
+    assert_eq!(s.parse_list_number().unwrap_err().to_string(), "unexpected end of stream");
+}
+
+#[test]
+fn list_number_end_2() {
+    let mut s = Stream::from_str("1, ");
+    assert_eq!(s.parse_list_number().unwrap(), 1.0);
+    assert_eq!(s.parse_list_number().unwrap_err().to_string(), "unexpected end of stream");
+}
+
+#[test]
+fn list_length_end_1() {
+    let mut s = Stream::from_str(" ");
+    assert_eq!(s.parse_list_length().unwrap_err().to_string(), "unexpected end of stream");
+}
+
+#[test]
+fn lengths_into_1() {
+    let mut buf = [Length::new(0.0, LengthUnit::None); 2];
+    let mut s = Stream::from_str("3 5");
+    assert_eq!(s.parse_lengths_into(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [Length::new(3.0, LengthUnit::None), Length::new(5.0, LengthUnit::None)]);
+}
+
+#[test]
+fn lengths_into_2() {
+    let mut buf = [Length::new(0.0, LengthUnit::None); 2];
+    let mut s = Stream::from_str(" 10% ");
+    assert_eq!(s.parse_lengths_into(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], Length::new(10.0, LengthUnit::Percent));
+}
+
+#[test]
+fn lengths_into_err_1() {
+    let mut buf = [Length::new(0.0, LengthUnit::None); 2];
+    let mut s = Stream::from_str("1mm, x");
+    assert_eq!(s.parse_lengths_into(&mut buf).unwrap_err().to_string(),
+               "invalid number at 1:6");
+}
+
+macro_rules! test_peek_number {
+    ($name:ident, $text:expr, $result:expr) => (
+        #[test]
+        fn $name() {
+            let s = Stream::from_str($text);
+            assert_eq!(s.peek_is_number(), $result);
+            assert_eq!(s.pos(), 0);
+        }
+    )
+}
+
+test_peek_number!(peek_number_1, "-.5", true);
+test_peek_number!(peek_number_2, "10px", true);
+test_peek_number!(peek_number_3, "+1", true);
+test_peek_number!(peek_number_4, ".1", true);
+test_peek_number!(peek_number_5, "abc", false);
+test_peek_number!(peek_number_6, "e2", false);
+test_peek_number!(peek_number_7, "-", false);
+test_peek_number!(peek_number_8, "-.", false);
+test_peek_number!(peek_number_9, " 1", false);
+test_peek_number!(peek_number_10, "", false);
+
+#[test]
+fn take_1() {
+    let mut s = Stream::from_str("Some text");
+    assert_eq!(s.take(4).unwrap(), "Some");
+    assert_eq!(s.take(5).unwrap(), " text");
+    assert!(s.at_end());
+}
+
+#[test]
+fn take_2() {
+    let mut s = Stream::from_str("text");
+    assert_eq!(s.take(0).unwrap(), "");
+    assert_eq!(s.pos(), 0);
+}
+
+#[test]
+fn take_err_1() {
+    let mut s = Stream::from_str("text");
+    assert_eq!(s.take(5).unwrap_err().to_string(), "unexpected end of stream");
+    assert_eq!(s.pos(), 0);
+}
+
+#[test]
+fn take_err_2() {
+    let mut s = Stream::from_str("aф");
+    assert_eq!(s.take(2).unwrap_err().to_string(), "expected 'a char boundary' not 'ф' at 1:2");
+    assert_eq!(s.take(3).unwrap(), "aф");
+}
+
+#[test]
+fn balanced_parens_1() {
+    let mut s = Stream::from_str("(a(b)c)");
+    assert_eq!(s.consume_balanced_parens().unwrap(), "a(b)c");
+    assert!(s.at_end());
+}
+
+#[test]
+fn balanced_parens_2() {
+    let mut s = Stream::from_str("()()");
+    assert_eq!(s.consume_balanced_parens().unwrap(), "");
+    assert_eq!(s.slice_tail().to_str(), "()");
+}
+
+#[test]
+fn balanced_parens_err_1() {
+    let mut s = Stream::from_str("(a(b");
+    assert_eq!(s.consume_balanced_parens().unwrap_err().to_string(), "unexpected end of stream");
+    assert_eq!(s.pos(), 0);
+}
+
+#[test]
+fn balanced_parens_err_2() {
+    let mut s = Stream::from_str("a)");
+    assert_eq!(s.consume_balanced_parens().unwrap_err().to_string(), "expected '(' not 'a' at 1:1");
+}
+
+#[test]
+fn quoted_string_1() {
+    let mut s = Stream::from_str("'abc'");
+    assert_eq!(s.parse_quoted_string().unwrap().to_str(), "abc");
+    assert!(s.at_end());
+}
+
+#[test]
+fn quoted_string_2() {
+    let mut s = Stream::from_str("\"a'b\" c");
+    assert_eq!(s.parse_quoted_string().unwrap().to_str(), "a'b");
+    assert_eq!(s.slice_tail().to_str(), " c");
+}
+
+#[test]
+fn quoted_string_3() {
+    let mut s = Stream::from_str("''");
+    assert_eq!(s.parse_quoted_string().unwrap().to_str(), "");
+}
+
+#[test]
+fn quoted_string_err_1() {
+    let mut s = Stream::from_str("abc");
+    assert_eq!(s.parse_quoted_string().unwrap_err().to_string(),
+               "expected ''\"' not 'a' at 1:1");
+}
+
+#[test]
+fn quoted_string_err_2() {
+    let mut s = Stream::from_str("'abc");
+    assert_eq!(s.parse_quoted_string().unwrap_err().to_string(), "unexpected end of stream");
+}
+
+#[test]
+fn skip_spaces_long_1() {
+    let mut text = " \t\r\n".repeat(2500);
+    text.push_str("fill");
+    let mut s = Stream::from_str(&text);
+    s.skip_spaces();
+    assert_eq!(s.pos(), 10000);
+    assert_eq!(s.slice_tail().to_str(), "fill");
+}
+
+#[test]
+fn skip_spaces_long_2() {
+    // Spaces mixed with space character references.
+    let mut text = String::new();
+    for _ in 0..1000 {
+        text.push_str("  &#x20; &#9;");
+    }
+    text.push_str("&amp;");
+    let mut s = Stream::from_str(&text);
+    s.skip_spaces();
+    assert_eq!(s.slice_tail().to_str(), "&amp;");
+}
+
+#[test]
+fn parse_list_number_long_spaces_1() {
+    let text = format!("1{}2", " ".repeat(10000));
+    let mut s = Stream::from_str(&text);
+    assert_eq!(s.parse_list_number().unwrap(), 1.0);
+    assert_eq!(s.parse_list_number().unwrap(), 2.0);
+    assert!(s.at_end());
+}
@@ -1,9 +1,17 @@
 extern crate svgparser;
 
 use svgparser::{
+    path,
     style,
     xmlparser,
     AttributeId as AId,
+    AttributeValue,
+    Color,
+    ElementId,
+    Length,
+    LengthUnit,
+    StyleError,
+    ValueId,
 };
 
 use xmlparser::{
@@ -17,7 +25,7 @@ macro_rules! test_attr {
             let mut s = style::Tokenizer::from_str($text);
             $(
                 match s.next().unwrap().unwrap() {
-                    style::Token::SvgAttribute(aid, value) => {
+                    style::Token::SvgAttribute(aid, value, _) => {
                         assert_eq!(aid, $aid);
                         assert_eq!(value.to_str(), $avalue);
                     },
@@ -94,6 +102,102 @@ test_attr!(parse_style_14, "  /*text*/ fill:green  /*text*/ ",
     (AId::Fill, "green")
 );
 
+test_attr!(parse_style_15, "/*a*//*b*/fill:none",
+    (AId::Fill, "none")
+);
+
+test_attr!(parse_style_16, "fill:none/**/;/* a * b */stroke/**/:/**/red/**/",
+    (AId::Fill, "none"),
+    (AId::Stroke, "red")
+);
+
+// comments are not nested
+test_attr!(parse_style_17, "/*/**/fill:none",
+    (AId::Fill, "none")
+);
+
+test_attr!(parse_style_18, "font-family:\"Arial Bold\"",
+    (AId::FontFamily, "Arial Bold")
+);
+
+test_attr!(parse_style_19, "font-family:&quot;Verdana&quot;",
+    (AId::FontFamily, "Verdana")
+);
+
+test_attr!(parse_style_20, "font-family:\"A;B\"; fill:none",
+    (AId::FontFamily, "A;B"),
+    (AId::Fill, "none")
+);
+
+test_attr!(parse_style_21, "font-family:'A\"B'",
+    (AId::FontFamily, "A\"B")
+);
+
+macro_rules! test_important {
+    ($name:ident, $text:expr, $avalue:expr, $important:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = style::Tokenizer::from_str($text);
+            match s.next().unwrap().unwrap() {
+                style::Token::SvgAttribute(_, value, important) => {
+                    assert_eq!(value.to_str(), $avalue);
+                    assert_eq!(important, $important);
+                },
+                _ => unreachable!(),
+            }
+        }
+    )
+}
+
+test_important!(important_1, "fill:red!important", "red", true);
+test_important!(important_2, "fill: red ! important ;", "red", true);
+test_important!(important_3, "fill:red", "red", false);
+test_important!(important_4, "font-family:'Arial' !important", "Arial", true);
+test_important!(important_5, "fill:red !IMPORTANT", "red", true);
+test_important!(important_6, "fill:important", "important", false);
+test_important!(important_non_ascii_1, "font-family:ééééé", "ééééé", false);
+test_important!(important_non_ascii_2, "font-family:ééééé !important", "ééééé", true);
+
+#[test]
+fn important_7() {
+    let mut s = style::Tokenizer::from_str("fill:red !important; qwe:x !important");
+    assert!(s.next().unwrap().is_ok());
+    match s.next().unwrap().unwrap() {
+        style::Token::XmlAttribute(name, value, important) => {
+            assert_eq!(name, "qwe");
+            assert_eq!(value, "x");
+            assert!(important);
+        }
+        _ => unreachable!(),
+    }
+}
+
+macro_rules! test_xml_attr {
+    ($name:ident, $text:expr, $aname:expr, $avalue:expr) => (
+        #[test]
+        fn $name() {
+            let mut s = style::Tokenizer::from_str($text);
+            match s.next().unwrap().unwrap() {
+                style::Token::XmlAttribute(name, value, _) => {
+                    assert_eq!(name, $aname);
+                    assert_eq!(value, $avalue);
+                },
+                _ => unreachable!(),
+            }
+        }
+    )
+}
+
+test_xml_attr!(xml_attr_1, "qwe: a   b  ;", "qwe", "a   b");
+test_xml_attr!(xml_attr_2, "qwe:'  a   b '", "qwe", "  a   b ");
+test_xml_attr!(xml_attr_3, "qwe:\"\ta\nb\"", "qwe", "\ta\nb");
+test_xml_attr!(xml_attr_4, "qwe: a \t b !important", "qwe", "a \t b");
+
+// Quoted values of SVG attributes are still trimmed.
+test_attr!(svg_attr_quoted_1, "font-family:' Arial '",
+    (AId::FontFamily, "Arial")
+);
+
 #[test]
 fn invalid_1() {
     let mut s = style::Tokenizer::from_str(":");
@@ -117,8 +221,9 @@ fn invalid_3() {
 
 #[test]
 fn invalid_4() {
-    let mut s = style::Tokenizer::from_str("/*/**/");
-    assert_eq!(s.next().unwrap().is_err(), true);
+    let mut s = style::Tokenizer::from_str("/*/*");
+    assert_eq!(s.next().unwrap().unwrap_err().to_string(),
+               "unexpected end of stream");
 }
 
 #[test]
@@ -134,3 +239,81 @@ fn invalid_6() {
     assert_eq!(s.next().unwrap().unwrap_err().to_string(),
                "expected '/-&' not '{' at 1:1");
 }
+
+#[test]
+fn invalid_7() {
+    // parsing stops after the first error
+    let mut s = style::Tokenizer::from_str("0 0 1 1;");
+    assert_eq!(s.next().unwrap().unwrap_err().to_string(),
+               "expected ':' not '0' at 1:3");
+    assert!(s.next().is_none());
+}
+
+#[test]
+fn path_func_1() {
+    let mut s = style::Tokenizer::from_str("d: path(\"M0 0 L10 10\")");
+    let value = match s.next().unwrap().unwrap() {
+        style::Token::SvgAttribute(AId::D, value, _) => value,
+        _ => unreachable!(),
+    };
+
+    match AttributeValue::from_span(ElementId::Path, "", AId::D, value).unwrap() {
+        AttributeValue::Path(p) => {
+            let tokens: Vec<_> = p.collect();
+            assert_eq!(tokens, vec![
+                path::Token::MoveTo { abs: true, x: 0.0, y: 0.0 },
+                path::Token::LineTo { abs: true, x: 10.0, y: 10.0 },
+            ]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn remaining_1() {
+    let mut s = style::Tokenizer::from_str("fill:red;stroke:blue");
+    s.next();
+    let tail = s.remaining();
+    assert_eq!(tail.to_str(), "stroke:blue");
+    assert_eq!(tail.start(), 9);
+}
+
+#[test]
+fn typed_1() {
+    let s = style::Tokenizer::from_str("fill:red;stroke-width:2");
+    let tokens: Vec<_> = style::typed(s, ElementId::Rect).map(|t| t.unwrap()).collect();
+    assert_eq!(tokens, vec![
+        style::TypedToken::SvgAttribute(AId::Fill,
+            AttributeValue::Color(Color::new(255, 0, 0)), false),
+        style::TypedToken::SvgAttribute(AId::StrokeWidth,
+            AttributeValue::Length(Length::new(2.0, LengthUnit::None)), false),
+    ]);
+}
+
+#[test]
+fn typed_2() {
+    let s = style::Tokenizer::from_str("&ref; fill:none !important; qwe:123");
+    let tokens: Vec<_> = style::typed(s, ElementId::Rect).map(|t| t.unwrap()).collect();
+    assert_eq!(tokens, vec![
+        style::TypedToken::EntityRef("ref"),
+        style::TypedToken::SvgAttribute(AId::Fill,
+            AttributeValue::PredefValue(ValueId::None), true),
+        style::TypedToken::XmlAttribute("qwe", "123", false),
+    ]);
+}
+
+#[test]
+fn typed_err_1() {
+    let s = style::Tokenizer::from_str("stroke-width:q; fill:red");
+    let mut s = style::typed(s, ElementId::Rect);
+    // The position is relative to the style attribute.
+    match s.next().unwrap().unwrap_err() {
+        StyleError::Attribute(e) => {
+            assert_eq!(e.aid, AId::StrokeWidth);
+            assert_eq!(e.eid, ElementId::Rect);
+            assert_eq!(e.source.to_string(), "invalid number at 1:14");
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+    assert!(s.next().is_none());
+}
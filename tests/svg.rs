@@ -0,0 +1,304 @@
+extern crate svgparser;
+
+use svgparser::{
+    svg,
+    xmlparser,
+    AttributeId,
+    Color,
+    ElementId,
+    Length,
+    LengthUnit,
+};
+
+use svg::{
+    ElementEnd,
+    Name,
+    QName,
+    Token,
+};
+
+use xmlparser::{
+    FromSpan,
+    StrSpan,
+};
+
+fn element_start<'a>(p: &mut svg::Tokenizer<'a>) -> svg::TagName<'a> {
+    match p.next().unwrap().unwrap() {
+        Token::ElementStart(tag) => tag,
+        t => panic!("unexpected token: {:?}", t),
+    }
+}
+
+#[test]
+fn ns_prefix_ignored_by_default() {
+    let mut p = svg::Tokenizer::from_str("<other:rect/>");
+    let tag = element_start(&mut p);
+    assert_eq!(tag.prefix, "other");
+    assert_eq!(tag.local, Name::Svg(ElementId::Rect));
+}
+
+#[test]
+fn ns_svg_prefix() {
+    let mut p = svg::Tokenizer::from_str("<svg:rect/>");
+    p.set_svg_prefixes(&["svg"]);
+    let tag = element_start(&mut p);
+    assert_eq!(tag.prefix, "svg");
+    assert_eq!(tag.local, Name::Svg(ElementId::Rect));
+}
+
+#[test]
+fn ns_other_prefix() {
+    let mut p = svg::Tokenizer::from_str("<other:rect/>");
+    p.set_svg_prefixes(&["svg"]);
+    let tag = element_start(&mut p);
+    assert_eq!(tag.prefix, "other");
+    assert_eq!(tag.local, Name::Xml("rect"));
+}
+
+#[test]
+fn ns_default_namespace() {
+    let mut p = svg::Tokenizer::from_str("<svg><svg:rect/><rect/></svg>");
+    p.set_svg_prefixes(&["", "svg"]);
+    assert_eq!(element_start(&mut p).local, Name::Svg(ElementId::Svg));
+    p.next(); // ElementEnd
+    assert_eq!(element_start(&mut p).local, Name::Svg(ElementId::Rect));
+    p.next(); // ElementEnd
+    assert_eq!(element_start(&mut p).local, Name::Svg(ElementId::Rect));
+}
+
+#[test]
+fn ns_non_svg_default_namespace() {
+    let mut p = svg::Tokenizer::from_str("<rect></rect>");
+    p.set_svg_prefixes(&["svg"]);
+    assert_eq!(element_start(&mut p).local, Name::Xml("rect"));
+    p.next(); // ElementEnd::Open
+    match p.next().unwrap().unwrap() {
+        Token::ElementEnd(svg::ElementEnd::Close(tag)) => {
+            assert_eq!(tag.local, Name::Xml("rect"));
+        }
+        t => panic!("unexpected token: {:?}", t),
+    }
+}
+
+#[test]
+fn max_attributes_1() {
+    let mut p = svg::Tokenizer::from_str("<svg><rect x='1' y='2' width='3'/><rect x='1'/></svg>");
+    p.set_max_attributes(2);
+    element_start(&mut p);
+    p.next(); // ElementEnd
+    element_start(&mut p);
+    assert!(p.next().unwrap().is_ok()); // x
+    assert!(p.next().unwrap().is_ok()); // y
+    assert_eq!(p.next().unwrap().unwrap_err().to_string(),
+               "unexpected token 'Attribute' at 1:24");
+    p.next(); // ElementEnd
+    // counter is reset on the next element
+    element_start(&mut p);
+    assert!(p.next().unwrap().is_ok()); // x
+}
+
+#[test]
+fn max_attributes_2() {
+    // exactly at the limit
+    let mut p = svg::Tokenizer::from_str("<rect x='1' y='2'/>");
+    p.set_max_attributes(2);
+    for t in p {
+        assert!(t.is_ok());
+    }
+}
+
+#[test]
+fn bytes_consumed_1() {
+    let mut p = svg::Tokenizer::from_str("<svg x='1'><!--c-->text</svg>");
+    assert_eq!(p.bytes_consumed(), 0);
+
+    let mut prev = 0;
+    for (t, pos) in [4, 9, 9, 16, 23, 28].iter().enumerate() {
+        assert!(p.next().unwrap().is_ok(), "token {}", t);
+        assert_eq!(p.bytes_consumed(), *pos);
+        assert!(*pos >= prev);
+        prev = *pos;
+    }
+
+    assert!(p.next().is_none());
+}
+
+#[test]
+fn bytes_consumed_2() {
+    // relative to the input span
+    let text = "  <svg/>";
+    let mut p = svg::Tokenizer::from_span(StrSpan::from_substr(text, 2, text.len()));
+    p.next();
+    assert_eq!(p.bytes_consumed(), 4);
+}
+
+fn parse_element<'a>(text: &'a str) -> svg::Element<'a> {
+    let mut p = svg::Tokenizer::from_str(text);
+    let name = element_start(&mut p);
+    p.parse_element(name).unwrap()
+}
+
+#[test]
+fn element_1() {
+    let elem = parse_element("<rect x='10mm' fill='#ff0000' opacity='0.5'/>");
+    assert_eq!(elem.name.local, Name::Svg(ElementId::Rect));
+    assert_eq!(elem.end, ElementEnd::Empty);
+    assert_eq!(elem.attributes.len(), 3);
+    assert_eq!(elem.length(AttributeId::X), Some(Length::new(10.0, LengthUnit::Mm)));
+    assert_eq!(elem.color(AttributeId::Fill), Some(Color::new(255, 0, 0)));
+    assert_eq!(elem.number(AttributeId::Opacity), Some(0.5));
+}
+
+#[test]
+fn element_missing() {
+    let elem = parse_element("<rect x='10'></rect>");
+    assert_eq!(elem.end, ElementEnd::Open);
+    assert_eq!(elem.length(AttributeId::Y), None);
+    assert_eq!(elem.color(AttributeId::Fill), None);
+}
+
+#[test]
+fn element_malformed() {
+    let elem = parse_element("<rect x='q' fill='none'/>");
+    assert_eq!(elem.attribute(AttributeId::X).unwrap().to_str(), "q");
+    assert_eq!(elem.length(AttributeId::X), None);
+    // a valid value, but not a color
+    assert_eq!(elem.color(AttributeId::Fill), None);
+}
+
+#[test]
+fn element_unclosed() {
+    let mut p = svg::Tokenizer::from_str("<rect x='10'");
+    let name = element_start(&mut p);
+    assert_eq!(p.parse_element(name).unwrap_err().to_string(),
+               "invalid token 'Element Start' at 1:13");
+}
+
+#[test]
+fn start_tag_writer_1() {
+    let mut p = svg::Tokenizer::from_str("<rect x='1' y=\"2\"/>");
+    let mut tag = svg::StartTagWriter::new(element_start(&mut p));
+
+    loop {
+        match p.next().unwrap().unwrap() {
+            Token::Attribute(name, value) => tag.push_raw_attribute(name, value),
+            Token::ElementEnd(ElementEnd::Empty) => break,
+            t => panic!("unexpected token: {:?}", t),
+        }
+    }
+
+    assert_eq!(tag.write(true), "<rect x=\"1\" y=\"2\"/>");
+}
+
+#[test]
+fn start_tag_writer_2() {
+    let mut tag = svg::StartTagWriter::new(QName::new("svg", Name::Xml("custom")));
+    tag.push_attribute(QName::new("xlink", Name::Svg(AttributeId::Href)), "#a");
+    tag.push_attribute(QName::new("", Name::Xml("data")), "a<b & \"c\"");
+    assert_eq!(tag.write(false),
+               "<svg:custom xlink:href=\"#a\" data=\"a&lt;b &amp; &quot;c&quot;\">");
+}
+
+#[test]
+fn start_tag_writer_3() {
+    // Values from the tokenizer are already escaped.
+    let mut p = svg::Tokenizer::from_str("<text x=\"a&amp;b\" y='&quot;\"'/>");
+    let mut tag = svg::StartTagWriter::new(element_start(&mut p));
+
+    loop {
+        match p.next().unwrap().unwrap() {
+            Token::Attribute(name, value) => tag.push_raw_attribute(name, value),
+            Token::ElementEnd(ElementEnd::Empty) => break,
+            t => panic!("unexpected token: {:?}", t),
+        }
+    }
+
+    assert_eq!(tag.write(true), "<text x=\"a&amp;b\" y=\"&quot;&quot;\"/>");
+}
+
+#[test]
+fn closing_tag_mismatch_1() {
+    let mut p = svg::Tokenizer::from_str("<svg></g>");
+    p.set_check_closing_tags();
+    element_start(&mut p);
+    assert!(p.next().unwrap().is_ok());
+    assert_eq!(p.next().unwrap().unwrap_err().to_string(),
+               "unexpected token 'Element Close' at 1:8");
+
+    let (expected, actual) = p.closing_tag_mismatch().unwrap();
+    assert_eq!(expected.local, Name::Svg(ElementId::Svg));
+    assert_eq!(actual.local, Name::Svg(ElementId::G));
+}
+
+#[test]
+fn closing_tag_mismatch_2() {
+    let mut p = svg::Tokenizer::from_str("<svg><g/><g></g></svg>");
+    p.set_check_closing_tags();
+    for token in p.by_ref() {
+        assert!(token.is_ok());
+    }
+
+    assert_eq!(p.closing_tag_mismatch(), None);
+}
+
+#[test]
+fn closing_tag_mismatch_3() {
+    // Disabled by default.
+    let mut p = svg::Tokenizer::from_str("<svg></g>");
+    for token in p.by_ref() {
+        assert!(token.is_ok());
+    }
+
+    assert_eq!(p.closing_tag_mismatch(), None);
+}
+
+#[test]
+fn skip_byte_order_1() {
+    let mut p = svg::Tokenizer::from_str("\u{FEFF}");
+    assert!(p.next().is_none());
+}
+
+#[test]
+fn skip_byte_order_2() {
+    let mut p = svg::Tokenizer::from_str("\u{FEFF}<svg/>");
+    assert_eq!(element_start(&mut p).local, Name::Svg(ElementId::Svg));
+    match p.next().unwrap().unwrap() {
+        Token::ElementEnd(ElementEnd::Empty) => {}
+        t => panic!("unexpected token: {:?}", t),
+    }
+    assert!(p.next().is_none());
+}
+
+#[test]
+fn has_bom_1() {
+    assert!(svg::has_bom("\u{FEFF}<svg/>"));
+    assert!(!svg::has_bom("<svg/>"));
+    assert!(!svg::has_bom(""));
+}
+
+#[test]
+fn elements_1() {
+    let elements: Vec<_> = svg::Elements::from_str("<svg x=\"1\" y=\"2\"><rect/></svg>")
+        .map(|e| e.unwrap()).collect();
+
+    assert_eq!(elements.len(), 2);
+
+    assert_eq!(elements[0].name.local, Name::Svg(ElementId::Svg));
+    assert_eq!(elements[0].end, ElementEnd::Open);
+    assert_eq!(elements[0].attributes.len(), 2);
+    assert_eq!(elements[0].length(AttributeId::X), Some(Length::new(1.0, LengthUnit::None)));
+    assert_eq!(elements[0].length(AttributeId::Y), Some(Length::new(2.0, LengthUnit::None)));
+
+    assert_eq!(elements[1].name.local, Name::Svg(ElementId::Rect));
+    assert_eq!(elements[1].end, ElementEnd::Empty);
+    assert!(elements[1].attributes.is_empty());
+}
+
+#[test]
+fn elements_err() {
+    let mut elements = svg::Elements::from_str("<svg><rect x='1'");
+    assert!(elements.next().unwrap().is_ok());
+    assert_eq!(elements.next().unwrap().unwrap_err().to_string(),
+               "invalid token 'Element Start' at 1:17");
+    assert!(elements.next().is_none());
+}
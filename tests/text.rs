@@ -0,0 +1,106 @@
+extern crate svgparser;
+
+use svgparser::xmlparser::{
+    FromSpan,
+    StrSpan,
+    TextUnescape,
+    XmlSpace,
+};
+use svgparser::{
+    path,
+    strip_comments,
+    transform,
+    TextUnescapeExt,
+};
+
+macro_rules! test {
+    ($name:ident, $text:expr, $space:expr, $result:expr) => {
+        #[test]
+        fn $name() {
+            let mut s = String::new();
+            TextUnescape::unescape_into($text, $space, &mut s);
+            assert_eq!(s, $result);
+        }
+    };
+}
+
+test!(unescape_into_1, "&gt;", XmlSpace::Default, ">");
+test!(unescape_into_2, "a  &#x20; b", XmlSpace::Default, "a b");
+test!(unescape_into_3, "a  b", XmlSpace::Preserve, "a  b");
+test!(unescape_into_4, "", XmlSpace::Default, "");
+
+#[test]
+fn unescape_into_reuse() {
+    let mut s = String::new();
+
+    TextUnescape::unescape_into("long text &amp; more", XmlSpace::Default, &mut s);
+    assert_eq!(s, "long text & more");
+    let cap = s.capacity();
+
+    TextUnescape::unescape_into("&lt;", XmlSpace::Default, &mut s);
+    assert_eq!(s, "<");
+    assert_eq!(s.capacity(), cap);
+}
+
+test!(char_ref_1, "&#x41;&#66;", XmlSpace::Default, "AB");
+test!(char_ref_surrogate, "&#xD800;", XmlSpace::Default, "&#xD800;");
+test!(char_ref_surrogate_dec, "a&#57343;b", XmlSpace::Default, "a&#57343;b");
+test!(char_ref_out_of_range, "&#x110000;", XmlSpace::Default, "&#x110000;");
+test!(char_ref_max, "&#x10FFFF;", XmlSpace::Default, "\u{10FFFF}");
+test!(char_ref_replacement, "&#xFFFD;&#65533;", XmlSpace::Default, "\u{FFFD}\u{FFFD}");
+test!(char_ref_unclosed, "&#x41", XmlSpace::Default, "&#x41");
+test!(char_ref_space, "a &#x20; b", XmlSpace::Default, "a b");
+
+test!(spaces_default_1, "  a  b  ", XmlSpace::Default, "a b");
+test!(spaces_default_2, "\n\ta\r\n", XmlSpace::Default, "a");
+test!(spaces_default_3, "   ", XmlSpace::Default, "");
+test!(spaces_preserve_1, "  a  b  ", XmlSpace::Preserve, "  a  b  ");
+
+macro_rules! test_strip {
+    ($name:ident, $text:expr, $result:expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(strip_comments(StrSpan::from_str($text)).unwrap(), $result);
+        }
+    };
+}
+
+test_strip!(strip_comments_1, "", "");
+test_strip!(strip_comments_2, "M 10 20", "M 10 20");
+test_strip!(strip_comments_3, "M 10 /*x*/ 20", "M 10       20");
+test_strip!(strip_comments_4, "/**/1/* * **/2", "    1        2");
+test_strip!(strip_comments_5, "1 /*a\nb*/ 2", "1    \n    2");
+test_strip!(strip_comments_6, "/*Ā*/1", "     1");
+
+#[test]
+fn strip_comments_err_1() {
+    assert_eq!(strip_comments(StrSpan::from_str("1 /* 2")).unwrap_err().to_string(),
+               "unexpected end of stream");
+}
+
+#[test]
+fn strip_comments_path_1() {
+    let text = "M 10 /*x*/ 20";
+
+    let mut p = path::Tokenizer::from_str(text);
+    assert_eq!(p.next_checked().unwrap().unwrap_err().to_string(),
+               "invalid number at 1:6");
+
+    let text = strip_comments(StrSpan::from_str(text)).unwrap();
+    let mut p = path::Tokenizer::from_str(&text);
+    assert_eq!(p.next().unwrap(), path::Token::MoveTo { abs: true, x: 10.0, y: 20.0 });
+    assert!(p.next().is_none());
+}
+
+#[test]
+fn strip_comments_transform_1() {
+    let text = "scale(/* x */2)";
+
+    let mut ts = transform::Tokenizer::from_str(text);
+    assert!(ts.next().unwrap().is_err());
+
+    let text = strip_comments(StrSpan::from_str(text)).unwrap();
+    let mut ts = transform::Tokenizer::from_str(&text);
+    assert_eq!(ts.next().unwrap().unwrap(), transform::Token::Scale { sx: 2.0, sy: 2.0 });
+    assert!(ts.next().is_none());
+}
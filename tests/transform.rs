@@ -4,12 +4,17 @@ use svgparser::{
     xmlparser,
 };
 use svgparser::transform::{
+    simplify,
+    simplify_to_string,
+    Matrix,
+    MatrixTokenizer,
     Tokenizer,
     Token,
 };
 
 use xmlparser::{
     FromSpan,
+    StrSpan,
 };
 
 macro_rules! test {
@@ -85,6 +90,22 @@ test!(ts_list_2, "translate(10,20), scale(2) ,  rotate(45),",
     Token::Rotate { angle: 45.0 }
 );
 
+test!(ts_list_3, "translate(1 2) , rotate(45)",
+    Token::Translate { tx: 1.0, ty: 2.0 },
+    Token::Rotate { angle: 45.0 }
+);
+
+test!(ts_list_4, "scale(2),scale(3)",
+    Token::Scale { sx: 2.0, sy: 2.0 },
+    Token::Scale { sx: 3.0, sy: 3.0 }
+);
+
+test!(ts_list_5, "translate(1,2),,\n rotate(45) ,\t, , scale(2)",
+    Token::Translate { tx: 1.0, ty: 2.0 },
+    Token::Rotate { angle: 45.0 },
+    Token::Scale { sx: 2.0, sy: 2.0 }
+);
+
 #[test]
 fn error_1() {
     let mut ts = Tokenizer::from_str("text");
@@ -106,9 +127,144 @@ fn error_3() {
     assert_eq!(ts.next().is_none(), true);
 }
 
+#[test]
+fn error_5() {
+    // parsing stops after the first error
+    let mut ts = Tokenizer::from_str("1 scale(2)");
+    assert_eq!(ts.next().unwrap().unwrap_err().to_string(),
+               "invalid name token");
+    assert!(ts.next().is_none());
+}
+
 #[test]
 fn error_4() {
     let mut ts = Tokenizer::from_str("???G");
     assert_eq!(ts.next().unwrap().unwrap_err().to_string(),
                "invalid name token");
 }
+
+#[test]
+fn parse_all_reporting_1() {
+    use svgparser::transform::parse_all_reporting;
+    let (tokens, errors) = parse_all_reporting(StrSpan::from_str("scale(2) bad(1) rotate(3)"));
+    assert_eq!(tokens, vec![
+        Token::Scale { sx: 2.0, sy: 2.0 },
+        Token::Rotate { angle: 3.0 },
+    ]);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "invalid transform prefix at 1:10");
+}
+
+#[test]
+fn parse_all_reporting_2() {
+    use svgparser::transform::parse_all_reporting;
+    let (tokens, errors) = parse_all_reporting(
+        StrSpan::from_str("bad(1), translate(10) scale(x), rotate(90 1 2) skewX("));
+    assert_eq!(tokens, vec![
+        Token::Translate { tx: 10.0, ty: 0.0 },
+        Token::Translate { tx: 1.0, ty: 2.0 },
+        Token::Rotate { angle: 90.0 },
+        Token::Translate { tx: -1.0, ty: -2.0 },
+    ]);
+    let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors, vec![
+        "invalid transform prefix at 1:1",
+        "invalid number at 1:29",
+        "unexpected end of stream",
+    ]);
+}
+
+macro_rules! test_simplify {
+    ($name:ident, $text:expr, $result:expr) => (
+        #[test]
+        fn $name() {
+            let s = simplify_to_string(StrSpan::from_str($text)).unwrap();
+            assert_eq!(s, $result);
+        }
+    )
+}
+
+test_simplify!(simplify_1, "translate(0) scale(1)", "");
+test_simplify!(simplify_2, "", "");
+test_simplify!(simplify_3, "rotate(45) rotate(-45)", "");
+test_simplify!(simplify_4, "translate(10) translate(5 5)", "translate(15 5)");
+test_simplify!(simplify_5, "scale(2) scale(3 1)", "scale(6 2)");
+test_simplify!(simplify_6, "rotate(30) rotate(60)", "rotate(90)");
+test_simplify!(simplify_7, "translate(10 20) scale(2)", "matrix(2 0 0 2 10 20)");
+test_simplify!(simplify_8, "scale(2) translate(10 20)", "matrix(2 0 0 2 20 40)");
+test_simplify!(simplify_9, "translate(10) scale(2) translate(-5)", "scale(2)");
+
+#[test]
+fn simplify_matrix() {
+    let m = simplify(StrSpan::from_str("translate(10 20) rotate(90) scale(2)")).unwrap();
+    let expected = Matrix::new(0.0, 2.0, -2.0, 0.0, 10.0, 20.0);
+    for &(a, b) in &[(m.a, expected.a), (m.b, expected.b), (m.c, expected.c),
+                     (m.d, expected.d), (m.e, expected.e), (m.f, expected.f)] {
+        assert!((a - b).abs() < 1e-10, "{:?}", m);
+    }
+}
+
+#[test]
+fn simplify_err() {
+    assert_eq!(simplify(StrSpan::from_str("scale(2) q(1)")).unwrap_err().to_string(),
+               "invalid transform prefix at 1:10");
+}
+
+#[test]
+fn matrix_tokenizer_1() {
+    let mut ts = MatrixTokenizer::from_str("translate(10 0) scale(2)");
+    let m1 = ts.next().unwrap().unwrap();
+    assert_eq!(m1, Matrix::new(1.0, 0.0, 0.0, 1.0, 10.0, 0.0));
+    let m2 = ts.next().unwrap().unwrap();
+    assert_eq!(m2, m1.multiply(&Matrix::new(2.0, 0.0, 0.0, 2.0, 0.0, 0.0)));
+    assert_eq!(m2, Matrix::new(2.0, 0.0, 0.0, 2.0, 10.0, 0.0));
+    assert!(ts.next().is_none());
+}
+
+#[test]
+fn matrix_tokenizer_2() {
+    assert!(MatrixTokenizer::from_str("").next().is_none());
+}
+
+#[test]
+fn matrix_tokenizer_3() {
+    let mut ts = MatrixTokenizer::from_str("scale(2) q(1)");
+    assert!(ts.next().unwrap().is_ok());
+    assert!(ts.next().unwrap().is_err());
+}
+
+#[test]
+fn display() {
+    assert_eq!(Token::Translate { tx: 10.0, ty: 0.0 }.to_string(), "translate(10)");
+    assert_eq!(Token::Scale { sx: 2.0, sy: 2.0 }.to_string(), "scale(2)");
+    assert_eq!(Token::Rotate { angle: -1.5 }.to_string(), "rotate(-1.5)");
+}
+
+#[test]
+fn error_span() {
+    use svgparser::StreamError;
+
+    let mut ts = Tokenizer::from_str("scale(2) qwe(1)");
+    ts.next();
+    match ts.next().unwrap() {
+        Err(StreamError::InvalidTransformPrefixSpan(span)) => {
+            assert_eq!(span.start.to_string(), "1:10");
+            assert_eq!(span.end.to_string(), "1:12");
+        }
+        e => panic!("unexpected result: {:?}", e),
+    }
+}
+
+#[test]
+fn remaining_1() {
+    let mut ts = Tokenizer::from_str("scale(2) foo");
+    assert_eq!(ts.next().unwrap().unwrap(), Token::Scale { sx: 2.0, sy: 2.0 });
+    assert!(ts.remaining().to_str().starts_with(" foo"));
+}
+
+#[test]
+fn remaining_2() {
+    let mut ts = Tokenizer::from_str("scale(2) , foo");
+    ts.next();
+    assert_eq!(ts.remaining().to_str(), " foo");
+}
@@ -0,0 +1,278 @@
+extern crate svgparser;
+
+use svgparser::{
+    xmlparser,
+    CommaList,
+    KeySplines,
+    KeyTimes,
+    Length,
+    LengthList,
+    LengthUnit,
+    NumberList,
+    ShapeList,
+    ShapeRef,
+    SpaceList,
+    ValuesList,
+};
+
+use xmlparser::{
+    FromSpan,
+    StrSpan,
+};
+
+#[test]
+fn number_list_collect_1() {
+    let list = NumberList::from_str("1 2,3 , 4");
+    assert_eq!(list.try_collect().unwrap(), vec![1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn number_list_collect_2() {
+    let list = NumberList::from_str("");
+    assert_eq!(list.try_collect().unwrap(), Vec::<f64>::new());
+}
+
+#[test]
+fn number_list_collect_3() {
+    // trailing spaces and separators are not an error
+    let list = NumberList::from_str("1, ");
+    assert_eq!(list.try_collect().unwrap(), vec![1.0]);
+}
+
+#[test]
+fn number_list_collect_4() {
+    let list = NumberList::from_str("  ");
+    assert_eq!(list.try_collect().unwrap(), Vec::<f64>::new());
+}
+
+#[test]
+fn number_list_collect_err_1() {
+    let list = NumberList::from_str("1 2 x 3");
+    assert_eq!(list.try_collect().unwrap_err().to_string(), "invalid number at 1:5");
+}
+
+#[test]
+fn number_list_fused_1() {
+    let mut list = NumberList::from_str("1 2");
+    assert_eq!(list.next().unwrap().unwrap(), 1.0);
+    assert_eq!(list.next().unwrap().unwrap(), 2.0);
+    assert!(list.next().is_none());
+    assert!(list.next().is_none());
+}
+
+#[test]
+fn number_list_fused_2() {
+    let mut list = NumberList::from_str("1 x 3");
+    assert_eq!(list.next().unwrap().unwrap(), 1.0);
+    assert!(list.next().unwrap().is_err());
+    assert!(list.next().is_none());
+    assert!(list.next().is_none());
+}
+
+#[test]
+fn number_list_size_hint_1() {
+    assert_eq!(NumberList::from_str("").size_hint(), (0, Some(0)));
+    assert_eq!(NumberList::from_str("1").size_hint(), (0, Some(1)));
+    assert_eq!(NumberList::from_str("1 2 3").size_hint(), (0, Some(3)));
+    assert_eq!(NumberList::from_str("1-2-3").size_hint(), (0, Some(3)));
+
+    let mut list = NumberList::from_str("1 2 3");
+    list.next();
+    assert_eq!(list.size_hint(), (0, Some(2)));
+}
+
+#[test]
+fn length_list_collect_1() {
+    let list = LengthList::from_span(StrSpan::from_str("1px 2% 3"));
+    assert_eq!(list.try_collect().unwrap(), vec![
+        Length::new(1.0, LengthUnit::Px),
+        Length::new(2.0, LengthUnit::Percent),
+        Length::new(3.0, LengthUnit::None),
+    ]);
+}
+
+#[test]
+fn length_list_fused_1() {
+    let mut list = LengthList::from_span(StrSpan::from_str("1mm x"));
+    assert_eq!(list.next().unwrap().unwrap(), Length::new(1.0, LengthUnit::Mm));
+    assert!(list.next().unwrap().is_err());
+    assert!(list.next().is_none());
+    assert!(list.next().is_none());
+}
+
+#[test]
+fn length_list_size_hint_1() {
+    let list = LengthList::from_span(StrSpan::from_str("1mm 2"));
+    assert_eq!(list.size_hint(), (0, Some(3)));
+    assert_eq!(list.count(), 2);
+}
+
+#[test]
+fn length_list_collect_2() {
+    let list = LengthList::from_span(StrSpan::from_str(" 1mm , "));
+    assert_eq!(list.try_collect().unwrap(), vec![Length::new(1.0, LengthUnit::Mm)]);
+}
+
+#[test]
+fn length_list_collect_err_1() {
+    let list = LengthList::from_span(StrSpan::from_str("1 2 x 3"));
+    assert_eq!(list.try_collect().unwrap_err().to_string(), "invalid number at 1:5");
+}
+
+#[test]
+fn number_list_display_1() {
+    let list = NumberList::from_str("1 2 3");
+    assert_eq!(list.to_string(), "1 2 3");
+    let text = list.to_string();
+    assert_eq!(NumberList::from_str(&text).try_collect().unwrap(), list.try_collect().unwrap());
+}
+
+#[test]
+fn number_list_display_2() {
+    assert_eq!(NumberList::from_str(" 1,-2.5 , 3e2 ").to_string(), "1 -2.5 300");
+}
+
+#[test]
+fn number_list_display_3() {
+    // stops at the first error
+    assert_eq!(NumberList::from_str("1 x 3").to_string(), "1");
+}
+
+#[test]
+fn length_list_display_1() {
+    let list = LengthList::from_span(StrSpan::from_str("1px 2em 3%"));
+    assert_eq!(list.to_string(), "1px 2em 3%");
+    let text = list.to_string();
+    assert_eq!(LengthList::from_span(StrSpan::from_str(&text)).try_collect().unwrap(),
+               list.try_collect().unwrap());
+}
+
+#[test]
+fn length_list_display_2() {
+    let list = LengthList::from_span(StrSpan::from_str("1px,2em , 3"));
+    assert_eq!(list.to_string(), "1px 2em 3");
+}
+
+#[test]
+fn shape_list_1() {
+    let list = ShapeList::from_str("url(#s)");
+    assert_eq!(list.try_collect().unwrap(), vec![ShapeRef::FuncIRI("s")]);
+}
+
+#[test]
+fn shape_list_2() {
+    let list = ShapeList::from_str("url(#s1), circle(50% at 10px 20px) url(#s2)");
+    assert_eq!(list.try_collect().unwrap(), vec![
+        ShapeRef::FuncIRI("s1"),
+        ShapeRef::BasicShape("circle(50% at 10px 20px)"),
+        ShapeRef::FuncIRI("s2"),
+    ]);
+}
+
+#[test]
+fn shape_list_3() {
+    let list = ShapeList::from_str("circle(calc(10px + 5%))");
+    assert_eq!(list.try_collect().unwrap(), vec![ShapeRef::BasicShape("circle(calc(10px + 5%))")]);
+}
+
+#[test]
+fn shape_list_err_1() {
+    let list = ShapeList::from_str("url(#s");
+    assert_eq!(list.try_collect().unwrap_err().to_string(), "unexpected end of stream");
+}
+
+#[test]
+fn shape_list_err_2() {
+    let list = ShapeList::from_str("circle(calc(10px)");
+    assert_eq!(list.try_collect().unwrap_err().to_string(), "unexpected end of stream");
+}
+
+#[test]
+fn values_list_1() {
+    let list: Vec<_> = ValuesList::from_str("0; 0.5; 1").collect();
+    assert_eq!(list.len(), 3);
+    assert_eq!(list[0].to_str(), "0");
+    assert_eq!(list[1].to_str(), "0.5");
+    assert_eq!(list[1].start(), 3);
+    assert_eq!(list[2].to_str(), "1");
+}
+
+#[test]
+fn values_list_2() {
+    let list: Vec<_> = ValuesList::from_str(" 0 0 ;; 10 20 ; ").map(|v| v.to_str()).collect();
+    assert_eq!(list, vec!["0 0", "10 20"]);
+}
+
+#[test]
+fn values_list_3() {
+    assert_eq!(ValuesList::from_str("  ").next(), None);
+}
+
+#[test]
+fn key_times_1() {
+    let list = KeyTimes::from_str("0;0.5;1");
+    assert_eq!(list.try_collect().unwrap(), vec![0.0, 0.5, 1.0]);
+}
+
+#[test]
+fn key_times_err_1() {
+    let list = KeyTimes::from_str("0; 1.5; 1");
+    assert_eq!(list.try_collect().unwrap_err().to_string(), "invalid number at 1:4");
+}
+
+#[test]
+fn key_times_err_2() {
+    let list = KeyTimes::from_str("0; 0.5 1");
+    assert_eq!(list.try_collect().unwrap_err().to_string(), "invalid number at 1:4");
+}
+
+#[test]
+fn key_splines_1() {
+    let list = KeySplines::from_str("0 0 1 1;0.5 0 0.5 1");
+    assert_eq!(list.try_collect().unwrap(), vec![
+        [0.0, 0.0, 1.0, 1.0],
+        [0.5, 0.0, 0.5, 1.0],
+    ]);
+}
+
+#[test]
+fn key_splines_2() {
+    let list = KeySplines::from_str(" 0, 0, 1, 1 ; ");
+    assert_eq!(list.try_collect().unwrap(), vec![[0.0, 0.0, 1.0, 1.0]]);
+}
+
+#[test]
+fn key_splines_err_1() {
+    let list = KeySplines::from_str("0 0 1 1; 0.5 0 0.5");
+    assert_eq!(list.try_collect().unwrap_err().to_string(),
+               "a key spline should contain four numbers at 1:10");
+}
+
+#[test]
+fn key_splines_err_2() {
+    let list = KeySplines::from_str("0 0 1 1 1");
+    assert_eq!(list.try_collect().unwrap_err().to_string(),
+               "a key spline should contain four numbers at 1:1");
+}
+
+#[test]
+fn comma_list_1() {
+    let list: Vec<_> = CommaList::from_str(" en-US ,fr,, de ").collect();
+    assert_eq!(list, vec!["en-US", "fr", "de"]);
+}
+
+#[test]
+fn comma_list_2() {
+    assert_eq!(CommaList::from_str(" , ").count(), 0);
+}
+
+#[test]
+fn space_list_1() {
+    let list: Vec<_> = SpaceList::from_str("http://a/b\n\thttp://c/d ").collect();
+    assert_eq!(list, vec!["http://a/b", "http://c/d"]);
+}
+
+#[test]
+fn space_list_2() {
+    assert_eq!(SpaceList::from_str("  ").count(), 0);
+}